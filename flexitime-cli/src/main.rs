@@ -1,17 +1,25 @@
+use std::process::ExitCode;
+
 use clap::Parser;
 use cli::Cli;
 
 mod cli;
 
-fn main() {
+fn main() -> ExitCode {
     let Cli { date , format} = Cli::parse();
 
-    let (_, result) = flexitime::parse_timestring(&date).unwrap();
-    
-    let chrono_time = result.to_chrono();
+    let chrono_time = match date.to_chrono() {
+        Ok(time) => time,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
     if let Some(fmt) = format {
         println!("{}", chrono_time.format(&fmt))
     } else {
         println!("{}", chrono_time)
     }
+
+    ExitCode::SUCCESS
 }
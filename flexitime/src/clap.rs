@@ -0,0 +1,258 @@
+//! Value parsers for accepting times, durations and ranges as command line arguments.
+//!
+//! [`ParsedTime`], [`ParsedDuration`] and [`TimeRange`] can be used directly as argument types,
+//! in which case they are parsed with the default configuration:
+//!
+//! ```
+//! use clap::{Arg, Command};
+//! use flexitime::{clap::TimeParser, parser::ParsedTime};
+//!
+//! let matches = Command::new("logs")
+//!     .arg(Arg::new("since").long("since").value_parser(TimeParser::new()))
+//!     .get_matches_from(["logs", "--since", "yesterday 9am"]);
+//! let since = matches.get_one::<ParsedTime>("since").unwrap();
+//! ```
+
+use std::ffi::OsStr;
+
+use ::clap::{
+    Arg, Command, Error,
+    builder::{TypedValueParser, ValueParserFactory},
+    error::ErrorKind,
+};
+
+use crate::{
+    error::FlexitimeResult,
+    parser::{FlexiParser, ParsedDuration, ParsedTime, TimeRange},
+};
+
+/// Parses the whole of an argument's value, failing if any of it is left over
+fn parse_arg<T>(
+    cmd: &Command,
+    arg: Option<&Arg>,
+    value: &OsStr,
+    expected: &str,
+    parse: impl FnOnce(&str) -> FlexitimeResult<&str, T>,
+) -> Result<T, Error> {
+    let invalid = |reason: String| {
+        let arg = arg.map_or_else(|| "...".to_string(), ToString::to_string);
+        cmd.clone().error(
+            ErrorKind::ValueValidation,
+            format!(
+                "invalid value '{}' for '{arg}': {reason}",
+                value.to_string_lossy()
+            ),
+        )
+    };
+
+    let Some(input) = value.to_str() else {
+        return Err(invalid(format!("{expected} must be valid UTF-8")));
+    };
+
+    match parse(input.trim()) {
+        Ok(("", value)) => Ok(value),
+        Ok((rest, _)) => Err(invalid(format!("unexpected '{rest}' after {expected}"))),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            Err(invalid(format!("expected {expected}. {err}")))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(invalid(format!("incomplete {expected}"))),
+    }
+}
+
+/// Parses a point in time, such as `tomorrow 5pm` or `3 days ago`
+#[derive(Debug, Clone, Default)]
+pub struct TimeParser {
+    parser: FlexiParser,
+}
+
+impl TimeParser {
+    pub fn new() -> Self {
+        TimeParser::default()
+    }
+
+    /// Uses a parser with its own configuration, such as custom holidays
+    pub fn with_parser(parser: FlexiParser) -> Self {
+        Self { parser }
+    }
+}
+
+impl TypedValueParser for TimeParser {
+    type Value = ParsedTime;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        parse_arg(cmd, arg, value, "a time", |input| self.parser.parse(input))
+    }
+}
+
+impl ValueParserFactory for ParsedTime {
+    type Parser = TimeParser;
+
+    fn value_parser() -> Self::Parser {
+        TimeParser::new()
+    }
+}
+
+/// Parses a fixed length of time, such as `1h 30m`
+#[derive(Debug, Clone, Default)]
+pub struct DurationParser {
+    parser: FlexiParser,
+}
+
+impl DurationParser {
+    pub fn new() -> Self {
+        DurationParser::default()
+    }
+
+    /// Uses a parser with its own configuration, such as allowing calendar units
+    pub fn with_parser(parser: FlexiParser) -> Self {
+        Self { parser }
+    }
+}
+
+impl TypedValueParser for DurationParser {
+    type Value = ParsedDuration;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        parse_arg(cmd, arg, value, "a duration", |input| {
+            self.parser.parse_duration(input)
+        })
+    }
+}
+
+impl ValueParserFactory for ParsedDuration {
+    type Parser = DurationParser;
+
+    fn value_parser() -> Self::Parser {
+        DurationParser::new()
+    }
+}
+
+/// Parses a span of time, such as `from 9am to 5pm` or `last 7 days`
+#[derive(Debug, Clone, Default)]
+pub struct RangeParser {
+    parser: FlexiParser,
+}
+
+impl RangeParser {
+    pub fn new() -> Self {
+        RangeParser::default()
+    }
+
+    /// Uses a parser with its own configuration, such as custom holidays
+    pub fn with_parser(parser: FlexiParser) -> Self {
+        Self { parser }
+    }
+}
+
+impl TypedValueParser for RangeParser {
+    type Value = TimeRange;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        parse_arg(cmd, arg, value, "a time range", |input| {
+            self.parser.parse_range(input)
+        })
+    }
+}
+
+impl ValueParserFactory for TimeRange {
+    type Parser = RangeParser;
+
+    fn value_parser() -> Self::Parser {
+        RangeParser::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use ::clap::value_parser;
+
+    use super::*;
+    use crate::parser::relative::time::RelativeTime;
+
+    fn command() -> Command {
+        Command::new("test")
+            .arg(
+                Arg::new("since")
+                    .long("since")
+                    .value_parser(value_parser!(ParsedTime)),
+            )
+            .arg(
+                Arg::new("timeout")
+                    .long("timeout")
+                    .value_parser(value_parser!(ParsedDuration)),
+            )
+            .arg(
+                Arg::new("window")
+                    .long("window")
+                    .value_parser(RangeParser::new()),
+            )
+    }
+
+    #[test]
+    fn test_parse_arguments() {
+        let matches = command()
+            .try_get_matches_from([
+                "test",
+                "--since",
+                "3 days ago",
+                "--timeout",
+                "1h 30m",
+                "--window",
+                "last 7 days",
+            ])
+            .unwrap();
+
+        assert_eq!(
+            matches.get_one::<ParsedTime>("since"),
+            Some(&ParsedTime::Relative(RelativeTime::new().days(3).ago()))
+        );
+        assert_eq!(
+            matches
+                .get_one::<ParsedDuration>("timeout")
+                .map(ParsedDuration::to_std),
+            Some(Duration::from_secs(5400))
+        );
+        assert!(matches.get_one::<TimeRange>("window").is_some());
+    }
+
+    #[test]
+    fn test_error_messages() {
+        let error = command()
+            .try_get_matches_from(["test", "--since", "tomorrow whenever"])
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::ValueValidation);
+        assert!(
+            error
+                .to_string()
+                .contains("invalid value 'tomorrow whenever' for '--since <since>': unexpected 'whenever' after a time"),
+            "{error}"
+        );
+
+        let error = command()
+            .try_get_matches_from(["test", "--timeout", "2 months"])
+            .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("expected a duration. Months do not have a fixed length"),
+            "{error}"
+        );
+    }
+}
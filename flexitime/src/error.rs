@@ -3,16 +3,35 @@ use thiserror::Error;
 
 use crate::parser::{
     absolute::{date::AbsoluteDateError, wallclock_time::WallClockTimeError},
+    duration::DurationError,
     relative::units::RelativeUnitsError,
+    typos::Suggestion,
 };
 
 #[derive(Debug, PartialEq, Error)]
 pub enum FlexitimeError<I> {
+    #[error("Could not parse the day offset")]
     DayOffsetParse,
+
+    #[error(transparent)]
     ParseIntError(#[from] std::num::ParseIntError),
+
+    #[error(transparent)]
     WallClockTime(#[from] WallClockTimeError),
+
+    #[error(transparent)]
     RelativeUnits(#[from] RelativeUnitsError),
+
+    #[error(transparent)]
     Date(#[from] AbsoluteDateError),
+
+    #[error(transparent)]
+    Duration(#[from] DurationError),
+
+    #[error(transparent)]
+    Suggestion(#[from] Suggestion),
+
+    #[error("Could not understand '{0}'")]
     Nom(I, nom::error::ErrorKind),
 }
 
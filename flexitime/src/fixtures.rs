@@ -0,0 +1,21 @@
+//! Dates and times shared by the tests
+
+use chrono::{NaiveDate, NaiveDateTime};
+
+/// The time tests resolve against, a Wednesday afternoon
+pub fn anchor() -> NaiveDateTime {
+    datetime(2025, 10, 22, 14, 30)
+}
+
+/// The day of [`anchor`]
+pub fn today() -> NaiveDate {
+    anchor().date()
+}
+
+pub fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+pub fn datetime(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+    date(year, month, day).and_hms_opt(hour, minute, 0).unwrap()
+}
@@ -0,0 +1,252 @@
+use std::fmt;
+
+use chrono::{Datelike, Month, NaiveDate, TimeDelta, Weekday};
+
+use crate::parser::absolute::nth_weekday::nth_weekday_in_month;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum HolidayRule {
+    /// The same day every year, e.g. the 25th of December
+    Fixed { month: Month, day: u8 },
+    /// The nth weekday of a month, e.g. the fourth Thursday of November. Negative values of `n`
+    /// count back from the end of the month.
+    NthWeekday {
+        n: i8,
+        weekday: Weekday,
+        month: Month,
+    },
+    /// A number of days from Easter Sunday, e.g. `1` for Easter Monday
+    Easter(i16),
+}
+
+impl HolidayRule {
+    /// The date on which the holiday falls in `year`, if it occurs in that year at all
+    pub fn date_in(&self, year: i32) -> Option<NaiveDate> {
+        match self {
+            HolidayRule::Fixed { month, day } => {
+                NaiveDate::from_ymd_opt(year, month.number_from_month(), (*day).into())
+            }
+            HolidayRule::NthWeekday { n, weekday, month } => {
+                nth_weekday_in_month(year, month.number_from_month(), *n, *weekday)
+            }
+            HolidayRule::Easter(offset) => {
+                easter_sunday(year)?.checked_add_signed(TimeDelta::days((*offset).into()))
+            }
+        }
+    }
+}
+
+/// Computes the date of Easter Sunday in the Gregorian calendar using the anonymous computus
+pub fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year.rem_euclid(19);
+    let b = year.div_euclid(100);
+    let c = year.rem_euclid(100);
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Holiday {
+    names: Vec<String>,
+    pub rule: HolidayRule,
+}
+
+impl Holiday {
+    pub fn new(name: impl Into<String>, rule: HolidayRule) -> Self {
+        Self {
+            names: vec![name.into()],
+            rule,
+        }
+    }
+
+    /// Adds an alternative name by which the holiday can be referred to
+    pub fn alias(mut self, name: impl Into<String>) -> Self {
+        self.names.push(name.into());
+        self
+    }
+
+    /// The primary name of the holiday
+    pub fn name(&self) -> &str {
+        &self.names[0]
+    }
+
+    /// Every name of the holiday, starting with its primary name
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+}
+
+/// A source of named holidays, consulted when parsing holiday names and when skipping
+/// non-working days
+pub trait HolidayCalendar: fmt::Debug + Send + Sync {
+    /// Every holiday known to the calendar
+    fn holidays(&self) -> &[Holiday];
+
+    /// Looks up a holiday by any of its names, ignoring case
+    fn find(&self, name: &str) -> Option<&Holiday> {
+        self.holidays().iter().find(|holiday| {
+            holiday
+                .names()
+                .iter()
+                .any(|candidate| candidate.eq_ignore_ascii_case(name))
+        })
+    }
+
+    /// Whether any holiday in the calendar falls on `date`
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays()
+            .iter()
+            .any(|holiday| holiday.rule.date_in(date.year()) == Some(date))
+    }
+}
+
+/// A list of holidays, which can be extended with custom days
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HolidaySet {
+    holidays: Vec<Holiday>,
+}
+
+impl HolidaySet {
+    /// An empty set of holidays
+    pub fn new() -> Self {
+        HolidaySet::default()
+    }
+
+    /// The holidays recognised by default, covering common Western and US holidays
+    pub fn builtin() -> Self {
+        let fixed = |month, day| HolidayRule::Fixed { month, day };
+        let nth = |n, weekday, month| HolidayRule::NthWeekday { n, weekday, month };
+
+        HolidaySet::new()
+            .holiday(
+                Holiday::new("new year's day", fixed(Month::January, 1))
+                    .alias("new years day")
+                    .alias("new year"),
+            )
+            .holiday(
+                Holiday::new(
+                    "martin luther king day",
+                    nth(3, Weekday::Mon, Month::January),
+                )
+                .alias("mlk day"),
+            )
+            .holiday(
+                Holiday::new("valentine's day", fixed(Month::February, 14)).alias("valentines day"),
+            )
+            .holiday(
+                Holiday::new("presidents' day", nth(3, Weekday::Mon, Month::February))
+                    .alias("presidents day"),
+            )
+            .holiday(
+                Holiday::new("st patrick's day", fixed(Month::March, 17))
+                    .alias("st patricks day")
+                    .alias("saint patrick's day"),
+            )
+            .holiday(Holiday::new("good friday", HolidayRule::Easter(-2)))
+            .holiday(Holiday::new("easter", HolidayRule::Easter(0)).alias("easter sunday"))
+            .holiday(Holiday::new("easter monday", HolidayRule::Easter(1)))
+            .holiday(Holiday::new(
+                "memorial day",
+                nth(-1, Weekday::Mon, Month::May),
+            ))
+            .holiday(
+                Holiday::new("independence day", fixed(Month::July, 4))
+                    .alias("fourth of july")
+                    .alias("4th of july"),
+            )
+            .holiday(Holiday::new(
+                "labor day",
+                nth(1, Weekday::Mon, Month::September),
+            ))
+            .holiday(Holiday::new("halloween", fixed(Month::October, 31)))
+            .holiday(Holiday::new(
+                "thanksgiving",
+                nth(4, Weekday::Thu, Month::November),
+            ))
+            .holiday(Holiday::new("christmas eve", fixed(Month::December, 24)))
+            .holiday(
+                Holiday::new("christmas", fixed(Month::December, 25))
+                    .alias("christmas day")
+                    .alias("xmas"),
+            )
+            .holiday(Holiday::new("boxing day", fixed(Month::December, 26)))
+            .holiday(
+                Holiday::new("new year's eve", fixed(Month::December, 31)).alias("new years eve"),
+            )
+    }
+
+    pub fn holiday(mut self, holiday: Holiday) -> Self {
+        self.register(holiday);
+        self
+    }
+
+    /// Adds a holiday. Holidays registered later take precedence when names clash.
+    pub fn register(&mut self, holiday: Holiday) {
+        self.holidays.insert(0, holiday);
+    }
+}
+
+impl HolidayCalendar for HolidaySet {
+    fn holidays(&self) -> &[Holiday] {
+        &self.holidays
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::date;
+
+    #[test]
+    fn test_easter_sunday() {
+        assert_eq!(easter_sunday(2024), Some(date(2024, 3, 31)));
+        assert_eq!(easter_sunday(2025), Some(date(2025, 4, 20)));
+        assert_eq!(easter_sunday(2026), Some(date(2026, 4, 5)));
+        assert_eq!(easter_sunday(2038), Some(date(2038, 4, 25)));
+    }
+
+    #[test]
+    fn test_rule_dates() {
+        let calendar = HolidaySet::builtin();
+
+        assert_eq!(
+            calendar.find("Thanksgiving").unwrap().rule.date_in(2025),
+            Some(date(2025, 11, 27))
+        );
+        assert_eq!(
+            calendar.find("easter monday").unwrap().rule.date_in(2026),
+            Some(date(2026, 4, 6))
+        );
+        assert_eq!(
+            calendar.find("new years eve").unwrap().rule.date_in(2025),
+            Some(date(2025, 12, 31))
+        );
+    }
+
+    #[test]
+    fn test_custom_holiday() {
+        let calendar = HolidaySet::new().holiday(Holiday::new(
+            "founders day",
+            HolidayRule::Fixed {
+                month: Month::June,
+                day: 12,
+            },
+        ));
+
+        assert!(calendar.is_holiday(date(2026, 6, 12)));
+        assert!(!calendar.is_holiday(date(2026, 12, 25)));
+    }
+}
@@ -0,0 +1,426 @@
+use chrono::{Datelike, Months, NaiveDateTime, NaiveTime, TimeDelta, Timelike};
+
+use crate::parser::{absolute::day_offset::weekday_name, relative::units::RelativeUnit};
+
+/// The units used when describing a length of time, from largest to smallest, along with their
+/// approximate length in seconds
+const UNITS: [(RelativeUnit, i64); 7] = [
+    (RelativeUnit::Years, 365 * 24 * 60 * 60),
+    (RelativeUnit::Months, 30 * 24 * 60 * 60),
+    (RelativeUnit::Weeks, 7 * 24 * 60 * 60),
+    (RelativeUnit::Days, 24 * 60 * 60),
+    (RelativeUnit::Hours, 60 * 60),
+    (RelativeUnit::Minutes, 60),
+    (RelativeUnit::Seconds, 1),
+];
+
+/// How many of each unit in [`UNITS`] make up the next larger one, where that is fixed
+fn per_larger_unit(index: usize) -> Option<i64> {
+    match index {
+        0 => None,
+        1 => Some(12),
+        // Months vary in length, so weeks are never carried into them
+        2 => None,
+        _ => Some(UNITS[index - 1].1 / UNITS[index].1),
+    }
+}
+
+/// Times closer than this are described relatively, even when they fall on another day
+const RELATIVE_WITHIN: TimeDelta = TimeDelta::hours(12);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Style {
+    /// Spelled out units and 12-hour times, e.g. `in 3 hours` or `tomorrow at 3pm`
+    #[default]
+    Long,
+    /// Abbreviated units and 24-hour times, e.g. `in 3h` or `tomorrow at 15:00`
+    Short,
+}
+
+/// Settings controlling how times are described
+#[derive(Debug, Clone, PartialEq)]
+pub struct HumanizeOptions {
+    pub style: Style,
+    /// The smallest unit to describe. Anything smaller is rounded away.
+    pub granularity: RelativeUnit,
+    /// The maximum number of units to use, e.g. `2` for `1 hour 30 minutes`
+    pub max_units: usize,
+}
+
+impl Default for HumanizeOptions {
+    fn default() -> Self {
+        Self {
+            style: Style::Long,
+            granularity: RelativeUnit::Seconds,
+            max_units: 1,
+        }
+    }
+}
+
+impl HumanizeOptions {
+    pub fn new() -> Self {
+        HumanizeOptions::default()
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn granularity(mut self, granularity: RelativeUnit) -> Self {
+        self.granularity = granularity;
+        self
+    }
+
+    pub fn max_units(mut self, max_units: usize) -> Self {
+        self.max_units = max_units.max(1);
+        self
+    }
+
+    /// The index into [`UNITS`] of the smallest unit to describe
+    fn smallest_unit(&self) -> usize {
+        match self.granularity {
+            RelativeUnit::Years => 0,
+            RelativeUnit::Quarters | RelativeUnit::Months => 1,
+            RelativeUnit::Weeks => 2,
+            RelativeUnit::Days | RelativeUnit::BusinessDays => 3,
+            RelativeUnit::Hours => 4,
+            RelativeUnit::Minutes => 5,
+            RelativeUnit::Seconds => 6,
+        }
+    }
+}
+
+fn unit_name(unit: &RelativeUnit, amount: i64, style: Style) -> String {
+    let (long, short) = match unit {
+        RelativeUnit::Years => ("year", "y"),
+        RelativeUnit::Months => ("month", "mo"),
+        RelativeUnit::Weeks => ("week", "w"),
+        RelativeUnit::Days => ("day", "d"),
+        RelativeUnit::Hours => ("hour", "h"),
+        RelativeUnit::Minutes => ("minute", "m"),
+        _ => ("second", "s"),
+    };
+
+    match (style, amount) {
+        (Style::Short, _) => format!("{amount}{short}"),
+        (Style::Long, 1) => format!("1 {long}"),
+        (Style::Long, _) => format!("{amount} {long}s"),
+    }
+}
+
+/// Splits the time between `from` and `to` into whole amounts of each unit. Years and months
+/// are counted on the calendar when `calendar` is set, otherwise they are left out.
+fn breakdown(from: NaiveDateTime, to: NaiveDateTime, calendar: bool) -> [i64; 7] {
+    let mut amounts = [0; 7];
+    let mut cursor = from;
+
+    if calendar {
+        let mut months = (to.year() - from.year()) * 12 + to.month() as i32 - from.month() as i32;
+        while months > 0
+            && from
+                .checked_add_months(Months::new(months as u32))
+                .is_none_or(|date| date > to)
+        {
+            months -= 1;
+        }
+
+        if let Some(date) = from.checked_add_months(Months::new(months.max(0) as u32)) {
+            cursor = date;
+            amounts[0] = i64::from(months.max(0) / 12);
+            amounts[1] = i64::from(months.max(0) % 12);
+        }
+    }
+
+    let mut seconds = (to - cursor).num_seconds();
+    for (amount, (_, length)) in amounts.iter_mut().zip(UNITS).skip(2) {
+        *amount = seconds / length;
+        seconds %= length;
+    }
+
+    amounts
+}
+
+/// Describes the amounts using at most `max_units` units no smaller than the granularity,
+/// rounding to the nearest amount of the smallest unit used and carrying into larger units, so
+/// that 59 minutes 40 seconds is described as `1 hour`. Returns `None` if the amounts round to
+/// nothing.
+fn describe(amounts: [i64; 7], options: &HumanizeOptions) -> Option<String> {
+    let smallest = options.smallest_unit();
+    let first = amounts
+        .iter()
+        .position(|amount| *amount != 0)
+        .unwrap_or(smallest)
+        .min(smallest);
+    let last = first
+        .saturating_add(options.max_units.saturating_sub(1))
+        .min(smallest);
+
+    let mut kept = amounts;
+    let remainder: i64 = (last + 1..UNITS.len())
+        .map(|index| amounts[index] * UNITS[index].1)
+        .sum();
+    if remainder * 2 >= UNITS[last].1 {
+        kept[last] += 1;
+
+        let mut index = last;
+        while let Some(per_larger) = per_larger_unit(index) {
+            if kept[index] < per_larger {
+                break;
+            }
+            kept[index] -= per_larger;
+            kept[index - 1] += 1;
+            index -= 1;
+        }
+    }
+    // Carrying may have moved the amount into a larger unit than the one it started in
+    let first = kept
+        .iter()
+        .position(|amount| *amount != 0)
+        .map_or(first, |index| index.min(first));
+
+    let parts = (first..=last)
+        .filter(|index| kept[*index] != 0)
+        .map(|index| unit_name(&UNITS[index].0, kept[index], options.style))
+        .collect::<Vec<_>>();
+
+    (!parts.is_empty()).then(|| parts.join(" "))
+}
+
+fn describe_time(time: NaiveTime, options: &HumanizeOptions) -> String {
+    let smallest = options.smallest_unit();
+    let (hour, minute, second) = (time.hour(), time.minute(), time.second());
+    let show_minutes = smallest >= 5 && (minute != 0 || second != 0);
+    let show_seconds = smallest >= 6 && second != 0;
+
+    // 12-hour times are avoided around midnight and noon, where am and pm are ambiguous
+    if options.style == Style::Short || hour == 0 || hour == 12 {
+        return if show_seconds {
+            format!("{hour:02}:{minute:02}:{second:02}")
+        } else if smallest >= 5 {
+            format!("{hour:02}:{minute:02}")
+        } else {
+            format!("{hour:02}:00")
+        };
+    }
+
+    let (hour, period) = if hour > 12 {
+        (hour - 12, "pm")
+    } else {
+        (hour, "am")
+    };
+    match (show_minutes, show_seconds) {
+        (_, true) => format!("{hour}:{minute:02}:{second:02}{period}"),
+        (true, false) => format!("{hour}:{minute:02}{period}"),
+        (false, false) => format!("{hour}{period}"),
+    }
+}
+
+/// Describes `target` relative to `anchor` as a phrase which [`crate::parse_timestring`] can
+/// parse, such as `in 3 hours`, `yesterday at 3pm`, `next tuesday` or `2 months ago`
+pub fn humanize(target: NaiveDateTime, anchor: NaiveDateTime, options: &HumanizeOptions) -> String {
+    let difference = target - anchor;
+    let days = (target.date() - anchor.date()).num_days();
+
+    if difference.abs() >= RELATIVE_WITHIN && (-1..=6).contains(&days) && days != 0 {
+        let day = match days {
+            -1 => "yesterday".to_string(),
+            1 => "tomorrow".to_string(),
+            _ => format!("next {}", weekday_name(target.weekday())),
+        };
+
+        return if options.smallest_unit() >= 4 {
+            format!("{day} at {}", describe_time(target.time(), options))
+        } else {
+            day
+        };
+    }
+
+    let description = if difference < TimeDelta::zero() {
+        describe(breakdown(target, anchor, true), options).map(|text| format!("{text} ago"))
+    } else {
+        describe(breakdown(anchor, target, true), options).map(|text| format!("in {text}"))
+    };
+
+    description.unwrap_or_else(|| "now".to_string())
+}
+
+/// Describes a length of time as a phrase which [`crate::parse_duration`] can parse, such as
+/// `3 hours` or `1h 30m`. Durations are described in weeks and smaller units, as months and
+/// years have no fixed length.
+pub fn humanize_duration(duration: TimeDelta, options: &HumanizeOptions) -> String {
+    let anchor = NaiveDateTime::default();
+    let amounts = breakdown(anchor, anchor + duration.abs(), false);
+
+    describe(amounts, options).unwrap_or_else(|| {
+        let smallest = UNITS[options.smallest_unit().max(2)].0.clone();
+        unit_name(&smallest, 0, options.style)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+        fixtures::{anchor, datetime},
+        parser::parse_timestring,
+        resolve::ResolveOptions,
+    };
+
+    use super::*;
+
+    /// Humanizes `target` and checks that parsing the result gives `target` back
+    fn round_trip(target: NaiveDateTime, options: &HumanizeOptions) -> String {
+        let text = humanize(target, anchor(), options);
+        let (rest, parsed) = parse_timestring(&text).unwrap();
+        assert_eq!(rest, "", "{text}");
+        assert_eq!(
+            parsed.resolve(anchor(), &ResolveOptions::default()),
+            Ok(target),
+            "{text}"
+        );
+        text
+    }
+
+    #[test]
+    fn test_humanize_relative() {
+        let options = HumanizeOptions::default();
+
+        assert_eq!(
+            round_trip(datetime(2025, 10, 22, 17, 30), &options),
+            "in 3 hours"
+        );
+        assert_eq!(
+            round_trip(datetime(2025, 10, 22, 14, 10), &options),
+            "20 minutes ago"
+        );
+        assert_eq!(
+            round_trip(datetime(2025, 8, 22, 14, 30), &options),
+            "2 months ago"
+        );
+        assert_eq!(
+            round_trip(datetime(2025, 11, 12, 14, 30), &options),
+            "in 3 weeks"
+        );
+        assert_eq!(round_trip(anchor(), &options), "now");
+    }
+
+    #[test]
+    fn test_humanize_named_days() {
+        let options = HumanizeOptions::default();
+
+        assert_eq!(
+            round_trip(datetime(2025, 10, 21, 15, 0), &options),
+            "yesterday at 3pm"
+        );
+        assert_eq!(
+            round_trip(datetime(2025, 10, 23, 9, 45), &options),
+            "tomorrow at 9:45am"
+        );
+        assert_eq!(
+            round_trip(datetime(2025, 10, 28, 12, 0), &options),
+            "next tuesday at 12:00"
+        );
+        assert_eq!(
+            humanize(
+                datetime(2025, 10, 28, 12, 0),
+                anchor(),
+                &options.clone().granularity(RelativeUnit::Days)
+            ),
+            "next tuesday"
+        );
+    }
+
+    #[test]
+    fn test_humanize_style_and_units() {
+        let short = HumanizeOptions::new().style(Style::Short).max_units(2);
+
+        assert_eq!(
+            round_trip(datetime(2025, 10, 22, 16, 0), &short),
+            "in 1h 30m"
+        );
+        assert_eq!(
+            round_trip(datetime(2025, 10, 23, 18, 15), &short),
+            "tomorrow at 18:15"
+        );
+
+        // Anything smaller than the last unit is rounded
+        assert_eq!(
+            humanize(
+                datetime(2025, 10, 22, 17, 50),
+                anchor(),
+                &HumanizeOptions::new()
+            ),
+            "in 3 hours"
+        );
+        assert_eq!(
+            humanize(
+                datetime(2025, 10, 22, 18, 0),
+                anchor(),
+                &HumanizeOptions::new()
+            ),
+            "in 4 hours"
+        );
+    }
+
+    #[test]
+    fn test_humanize_rounds_into_larger_units() {
+        let options = HumanizeOptions::new();
+
+        assert_eq!(
+            humanize(
+                anchor() + TimeDelta::seconds(59 * 60 + 40),
+                anchor(),
+                &options
+            ),
+            "in 1 hour"
+        );
+        assert_eq!(
+            humanize(
+                anchor() - TimeDelta::seconds(59 * 60 + 40),
+                anchor(),
+                &options
+            ),
+            "1 hour ago"
+        );
+        assert_eq!(
+            humanize_duration(
+                TimeDelta::seconds(23 * 60 * 60 + 59 * 60 + 40),
+                &options.clone().max_units(2)
+            ),
+            "1 day"
+        );
+        assert_eq!(
+            humanize_duration(
+                TimeDelta::hours(20),
+                &options.clone().granularity(RelativeUnit::Days)
+            ),
+            "1 day"
+        );
+        assert_eq!(
+            humanize_duration(
+                TimeDelta::days(6) + TimeDelta::hours(13),
+                &options.granularity(RelativeUnit::Days)
+            ),
+            "1 week"
+        );
+    }
+
+    #[test]
+    fn test_humanize_duration() {
+        let options = HumanizeOptions::new().max_units(3);
+
+        assert_eq!(
+            humanize_duration(TimeDelta::minutes(90), &options),
+            "1 hour 30 minutes"
+        );
+        assert_eq!(
+            humanize_duration(TimeDelta::days(45), &options.clone().style(Style::Short)),
+            "6w 3d"
+        );
+        assert_eq!(humanize_duration(TimeDelta::zero(), &options), "0 seconds");
+
+        let (_, parsed) = crate::parse_duration("1 hour 30 minutes").unwrap();
+        assert_eq!(parsed.to_time_delta(), TimeDelta::minutes(90));
+    }
+}
@@ -1,3 +1,17 @@
+#[cfg(feature = "clap")]
+pub mod clap;
 pub mod error;
+#[cfg(test)]
+mod fixtures;
+pub mod holiday;
+pub mod humanize;
 pub mod parser;
-pub use parser::parse_timestring;
+pub mod recurrence;
+pub mod resolve;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub use humanize::{HumanizeOptions, Style, humanize, humanize_duration};
+pub use parser::{FlexiParser, complete, find_all, parse_all, parse_duration, parse_timestring};
+pub use resolve::{
+    DayArithmetic, FoldPolicy, GapPolicy, MonthArithmetic, Preference, ResolveError, ResolveOptions,
+};
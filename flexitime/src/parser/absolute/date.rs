@@ -31,7 +31,7 @@ fn date_delimiter(input: &str) -> FlexitimeResult<&str, char> {
     alt((char('-'), char('/'))).parse(input)
 }
 
-fn parse_year(input: &str) -> FlexitimeResult<&str, u16> {
+pub(crate) fn parse_year(input: &str) -> FlexitimeResult<&str, u16> {
     map_res(
         take_while_m_n(4, 4, |c: char| c.is_ascii_digit()),
         |s: &str| {
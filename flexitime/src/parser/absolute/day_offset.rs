@@ -1,58 +1,167 @@
-use chrono::Weekday;
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate, TimeDelta, Weekday};
 use nom::{
     Parser,
     branch::alt,
     bytes::complete::tag_no_case,
-    character::complete::space1,
-    combinator::{opt, value},
+    character::complete::{alpha1, space1},
+    combinator::{map, not, value},
     sequence::preceded,
 };
 
-use crate::error::FlexitimeResult;
+use super::period_boundary::Period;
+use crate::{
+    error::FlexitimeResult,
+    parser::Locale,
+    resolve::{ResolveError, ResolveOptions},
+};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub enum DayOffset {
     Fixed(i32),
+    /// A weekday on its own, e.g. `friday`. Which occurrence is meant depends on the
+    /// [`Preference`](crate::resolve::Preference) it is resolved with.
+    Named(Weekday),
+    /// A weekday within the current week, which may already have passed, e.g. `this friday`.
+    /// The week begins on [`ResolveOptions::week_start`].
+    ThisDayOccurrence(Weekday),
+    /// The next occurrence of a weekday after today, e.g. `next friday`
     NextDayOccurrence(Weekday),
+    /// The most recent occurrence of a weekday before today, e.g. `last friday`
+    LastDayOccurrence(Weekday),
+    /// A number of business days from today, e.g. `next business day`
+    BusinessDays(i32),
+}
+
+impl DayOffset {
+    pub fn resolve(
+        &self,
+        today: NaiveDate,
+        options: &ResolveOptions,
+    ) -> Result<NaiveDate, ResolveError> {
+        let add_days = |days: i32| {
+            today
+                .checked_add_signed(TimeDelta::days(days.into()))
+                .ok_or(ResolveError::OutOfRange)
+        };
+        // Occurrences on today are skipped in both directions
+        let days_since = |weekday| match today.weekday().days_since(weekday) {
+            0 => 7,
+            days => days as i32,
+        };
+
+        let next = |weekday| add_days(7 - today.weekday().days_since(weekday) as i32);
+
+        match self {
+            DayOffset::Fixed(days) => add_days(*days),
+            DayOffset::Named(weekday) => options.preference.unwrap_or_default().choose(
+                today,
+                || add_days(-days_since(*weekday)),
+                || next(*weekday),
+            ),
+            DayOffset::ThisDayOccurrence(weekday) => {
+                let (week_start, _) = Period::Week.span(today, options)?;
+                week_start
+                    .checked_add_signed(TimeDelta::days(
+                        weekday.days_since(options.week_start).into(),
+                    ))
+                    .ok_or(ResolveError::OutOfRange)
+            }
+            DayOffset::NextDayOccurrence(weekday) => next(*weekday),
+            DayOffset::LastDayOccurrence(weekday) => add_days(-days_since(*weekday)),
+            DayOffset::BusinessDays(days) => options.add_business_days(today, *days),
+        }
+    }
+}
+
+impl fmt::Display for DayOffset {
+    /// Writes the offset as it would be typed, e.g. `tomorrow` or `friday`. Offsets which cannot
+    /// be named, such as `Fixed(3)`, are written relative to today.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DayOffset::Fixed(0) => write!(f, "today"),
+            DayOffset::Fixed(1) => write!(f, "tomorrow"),
+            DayOffset::Fixed(-1) => write!(f, "yesterday"),
+            DayOffset::Fixed(days) => write_relative_offset(f, *days, "day"),
+            DayOffset::Named(weekday) => write!(f, "{}", weekday_name(*weekday)),
+            DayOffset::ThisDayOccurrence(weekday) => write!(f, "this {}", weekday_name(*weekday)),
+            DayOffset::NextDayOccurrence(weekday) => write!(f, "next {}", weekday_name(*weekday)),
+            DayOffset::LastDayOccurrence(weekday) => write!(f, "last {}", weekday_name(*weekday)),
+            DayOffset::BusinessDays(1) => write!(f, "next business day"),
+            DayOffset::BusinessDays(-1) => write!(f, "previous business day"),
+            DayOffset::BusinessDays(days) => write_relative_offset(f, *days, "business day"),
+        }
+    }
+}
+
+/// Writes an offset of any size, e.g. `in 3 days` or `2 weeks ago`
+pub(crate) fn write_relative_offset(
+    f: &mut fmt::Formatter<'_>,
+    offset: i32,
+    unit: &str,
+) -> fmt::Result {
+    let plural = if offset.unsigned_abs() == 1 { "" } else { "s" };
+    if offset < 0 {
+        write!(f, "{} {unit}{plural} ago", offset.unsigned_abs())
+    } else {
+        write!(f, "in {offset} {unit}{plural}")
+    }
+}
+
+pub(crate) fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "monday",
+        Weekday::Tue => "tuesday",
+        Weekday::Wed => "wednesday",
+        Weekday::Thu => "thursday",
+        Weekday::Fri => "friday",
+        Weekday::Sat => "saturday",
+        Weekday::Sun => "sunday",
+    }
+}
+
+pub(crate) fn parse_weekday(input: &str) -> FlexitimeResult<&str, Weekday> {
+    Locale::ENGLISH.parse_weekday(input)
 }
 
 pub fn parse_day_offset(input: &str) -> FlexitimeResult<&str, DayOffset> {
     alt((
+        value(DayOffset::Fixed(0), tag_no_case("today")),
         value(DayOffset::Fixed(1), tag_no_case("tomorrow")),
         value(DayOffset::Fixed(-1), tag_no_case("yesterday")),
-        preceded(
-            opt((alt((tag_no_case("this"), tag_no_case("next"))), space1)),
-            alt((
-                value(
-                    DayOffset::NextDayOccurrence(Weekday::Mon),
-                    alt((tag_no_case("monday"), tag_no_case("mon"))),
-                ),
-                value(
-                    DayOffset::NextDayOccurrence(Weekday::Tue),
-                    alt((tag_no_case("tuesday"), tag_no_case("tue"))),
-                ),
-                value(
-                    DayOffset::NextDayOccurrence(Weekday::Wed),
-                    alt((tag_no_case("wednesday"), tag_no_case("wed"))),
-                ),
-                value(
-                    DayOffset::NextDayOccurrence(Weekday::Thu),
-                    alt((tag_no_case("thursday"), tag_no_case("thu"))),
-                ),
-                value(
-                    DayOffset::NextDayOccurrence(Weekday::Fri),
-                    alt((tag_no_case("friday"), tag_no_case("fri"))),
-                ),
-                value(
-                    DayOffset::NextDayOccurrence(Weekday::Sat),
-                    alt((tag_no_case("saturday"), tag_no_case("sat"))),
-                ),
-                value(
-                    DayOffset::NextDayOccurrence(Weekday::Sun),
-                    alt((tag_no_case("sunday"), tag_no_case("sun"))),
-                ),
-            )),
+        map(
+            (
+                alt((
+                    value(1, tag_no_case("next")),
+                    value(-1, tag_no_case("previous")),
+                    value(-1, tag_no_case("last")),
+                )),
+                space1,
+                alt((tag_no_case("business"), tag_no_case("working"))),
+                space1,
+                tag_no_case("day"),
+                not(alpha1),
+            ),
+            |(days, ..)| DayOffset::BusinessDays(days),
+        ),
+        map(
+            preceded(
+                (alt((tag_no_case("last"), tag_no_case("previous"))), space1),
+                parse_weekday,
+            ),
+            DayOffset::LastDayOccurrence,
         ),
+        map(
+            preceded((tag_no_case("this"), space1), parse_weekday),
+            DayOffset::ThisDayOccurrence,
+        ),
+        map(
+            preceded((tag_no_case("next"), space1), parse_weekday),
+            DayOffset::NextDayOccurrence,
+        ),
+        map(parse_weekday, DayOffset::Named),
     ))
     .parse(input)
 }
@@ -60,16 +169,17 @@ pub fn parse_day_offset(input: &str) -> FlexitimeResult<&str, DayOffset> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fixtures::{date, today};
 
     #[test]
     fn test_parse_weekday() {
         assert_eq!(
             parse_day_offset("monday"),
-            Ok(("", DayOffset::NextDayOccurrence(Weekday::Mon)))
+            Ok(("", DayOffset::Named(Weekday::Mon)))
         );
         assert_eq!(
             parse_day_offset("tue"),
-            Ok(("", DayOffset::NextDayOccurrence(Weekday::Tue)))
+            Ok(("", DayOffset::Named(Weekday::Tue)))
         );
     }
 
@@ -77,12 +187,67 @@ mod tests {
     fn test_parse_weekday_with_prefix() {
         assert_eq!(
             parse_day_offset("this wednesday"),
-            Ok(("", DayOffset::NextDayOccurrence(Weekday::Wed)))
+            Ok(("", DayOffset::ThisDayOccurrence(Weekday::Wed)))
         );
         assert_eq!(
             parse_day_offset("next thursday"),
             Ok(("", DayOffset::NextDayOccurrence(Weekday::Thu)))
         );
+        assert_eq!(
+            parse_day_offset("last friday"),
+            Ok(("", DayOffset::LastDayOccurrence(Weekday::Fri)))
+        );
+        assert_eq!(
+            parse_day_offset("previous mon"),
+            Ok(("", DayOffset::LastDayOccurrence(Weekday::Mon)))
+        );
+    }
+
+    #[test]
+    fn test_weekday_requires_word_boundary() {
+        assert!(parse_day_offset("next month").is_err());
+    }
+
+    #[test]
+    fn test_parse_business_day() {
+        assert_eq!(
+            parse_day_offset("next business day"),
+            Ok(("", DayOffset::BusinessDays(1)))
+        );
+        assert_eq!(
+            parse_day_offset("previous working day"),
+            Ok(("", DayOffset::BusinessDays(-1)))
+        );
+    }
+
+    #[test]
+    fn test_resolve_business_day() {
+        // A Friday
+        let today = NaiveDate::from_ymd_opt(2025, 10, 24).unwrap();
+        let options = ResolveOptions::default();
+
+        assert_eq!(
+            DayOffset::BusinessDays(1).resolve(today, &options),
+            NaiveDate::from_ymd_opt(2025, 10, 27).ok_or(ResolveError::OutOfRange)
+        );
+        assert_eq!(
+            DayOffset::BusinessDays(1)
+                .resolve(today, &options.weekend([Weekday::Fri, Weekday::Sat])),
+            NaiveDate::from_ymd_opt(2025, 10, 26).ok_or(ResolveError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_resolve_this_weekday_within_week() {
+        let resolve = |weekday, week_start| {
+            DayOffset::ThisDayOccurrence(weekday)
+                .resolve(today(), &ResolveOptions::new().week_start(week_start))
+        };
+
+        assert_eq!(resolve(Weekday::Sun, Weekday::Mon), Ok(date(2025, 10, 26)));
+        assert_eq!(resolve(Weekday::Sun, Weekday::Sun), Ok(date(2025, 10, 19)));
+        assert_eq!(resolve(Weekday::Mon, Weekday::Mon), Ok(date(2025, 10, 20)));
+        assert_eq!(resolve(Weekday::Wed, Weekday::Mon), Ok(today()));
     }
 
     #[test]
@@ -93,6 +258,11 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_today() {
+        assert_eq!(parse_day_offset("today"), Ok(("", DayOffset::Fixed(0))));
+    }
+
     #[test]
     fn test_parse_tomorrow() {
         assert_eq!(parse_day_offset("tomorrow"), Ok(("", DayOffset::Fixed(1))));
@@ -0,0 +1,351 @@
+use std::fmt;
+
+use chrono::{Datelike, Month, NaiveDate};
+use nom::{
+    Parser,
+    branch::alt,
+    bytes::complete::{tag_no_case, take_while_m_n},
+    character::complete::{alpha1, digit1, one_of, space0, space1},
+    combinator::{map, map_res, not, opt, value},
+    sequence::{preceded, terminated},
+};
+
+use super::{
+    date::parse_year,
+    period_boundary::{Period, parse_period_offset, write_offset},
+};
+use crate::{
+    error::FlexitimeResult,
+    resolve::{ResolveError, ResolveOptions},
+};
+
+/// The fiscal year containing `date`. Fiscal years are named after the calendar year in which
+/// they end, so with an October start `2025-11-01` falls in FY2026.
+pub fn fiscal_year_of(date: NaiveDate, start: Month) -> i32 {
+    if start != Month::January && date.month() >= start.number_from_month() {
+        date.year() + 1
+    } else {
+        date.year()
+    }
+}
+
+/// The first day of the given fiscal year
+pub fn fiscal_year_start(year: i32, start: Month) -> Result<NaiveDate, ResolveError> {
+    let calendar_year = if start == Month::January {
+        year
+    } else {
+        year - 1
+    };
+
+    NaiveDate::from_ymd_opt(calendar_year, start.number_from_month(), 1)
+        .ok_or(ResolveError::OutOfRange)
+}
+
+/// The number of whole months between the start of the fiscal year and `date`
+pub fn months_into_fiscal_year(date: NaiveDate, start: Month) -> u32 {
+    (date.month0() + 12 - start.number_from_month() + 1) % 12
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Quarter {
+    /// A numbered quarter of a fiscal year, e.g. `Q3` or `Q3 2025`. Without a year, the current
+    /// fiscal year is used.
+    Numbered { quarter: u8, year: Option<i32> },
+    /// A quarter counted from the current one, e.g. `next quarter`
+    Offset(i32),
+}
+
+impl fmt::Display for Quarter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Quarter::Numbered {
+                quarter,
+                year: Some(year),
+            } => write!(f, "Q{quarter} {year}"),
+            Quarter::Numbered {
+                quarter,
+                year: None,
+            } => write!(f, "Q{quarter}"),
+            Quarter::Offset(offset) => write_offset(f, *offset, Period::Quarter),
+        }
+    }
+}
+
+impl Quarter {
+    /// Returns the half-open `[start, end)` span of the quarter
+    pub fn span(
+        &self,
+        today: NaiveDate,
+        options: &ResolveOptions,
+    ) -> Result<(NaiveDate, NaiveDate), ResolveError> {
+        let start = match self {
+            Quarter::Numbered { quarter, year } => {
+                let fiscal_start = options.fiscal_year_start;
+                let year = year.unwrap_or_else(|| fiscal_year_of(today, fiscal_start));
+                Period::Quarter.shift(
+                    fiscal_year_start(year, fiscal_start)?,
+                    i32::from(*quarter) - 1,
+                )?
+            }
+            Quarter::Offset(offset) => {
+                let (current, _) = Period::Quarter.span(today, options)?;
+                Period::Quarter.shift(current, *offset)?
+            }
+        };
+
+        Period::Quarter.span(start, options)
+    }
+
+    pub fn resolve(
+        &self,
+        today: NaiveDate,
+        options: &ResolveOptions,
+    ) -> Result<NaiveDate, ResolveError> {
+        self.span(today, options).map(|(start, _)| start)
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum FiscalYear {
+    /// A fiscal year given by name, e.g. `FY26`
+    Numbered(i32),
+    /// A fiscal year counted from the current one, e.g. `next fiscal year`
+    Offset(i32),
+}
+
+impl fmt::Display for FiscalYear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FiscalYear::Numbered(year) => write!(f, "FY{year}"),
+            FiscalYear::Offset(offset) => write_offset(f, *offset, Period::FiscalYear),
+        }
+    }
+}
+
+impl FiscalYear {
+    /// Returns the half-open `[start, end)` span of the fiscal year
+    pub fn span(
+        &self,
+        today: NaiveDate,
+        options: &ResolveOptions,
+    ) -> Result<(NaiveDate, NaiveDate), ResolveError> {
+        let fiscal_start = options.fiscal_year_start;
+
+        let year = match self {
+            FiscalYear::Numbered(year) => *year,
+            FiscalYear::Offset(offset) => fiscal_year_of(today, fiscal_start) + offset,
+        };
+
+        Ok((
+            fiscal_year_start(year, fiscal_start)?,
+            fiscal_year_start(year + 1, fiscal_start)?,
+        ))
+    }
+
+    pub fn resolve(
+        &self,
+        today: NaiveDate,
+        options: &ResolveOptions,
+    ) -> Result<NaiveDate, ResolveError> {
+        self.span(today, options).map(|(start, _)| start)
+    }
+}
+
+fn parse_fiscal_year_number(input: &str) -> FlexitimeResult<&str, i32> {
+    preceded(
+        (tag_no_case("fy"), space0),
+        alt((
+            terminated(map(parse_year, i32::from), not(digit1)),
+            terminated(
+                map_res(
+                    take_while_m_n(2, 2, |c: char| c.is_ascii_digit()),
+                    |s: &str| s.parse::<i32>().map(|year| 2000 + year),
+                ),
+                not(digit1),
+            ),
+        )),
+    )
+    .parse(input)
+}
+
+fn parse_quarter_number(input: &str) -> FlexitimeResult<&str, u8> {
+    preceded(
+        tag_no_case("q"),
+        terminated(map(one_of("1234"), |c| c as u8 - b'0'), not(digit1)),
+    )
+    .parse(input)
+}
+
+pub fn parse_quarter(input: &str) -> FlexitimeResult<&str, Quarter> {
+    alt((
+        map(
+            (parse_fiscal_year_number, space1, parse_quarter_number),
+            |(year, _, quarter)| Quarter::Numbered {
+                quarter,
+                year: Some(year),
+            },
+        ),
+        map(
+            (
+                parse_quarter_number,
+                opt(preceded(
+                    space1,
+                    alt((parse_fiscal_year_number, map(parse_year, i32::from))),
+                )),
+            ),
+            |(quarter, year)| Quarter::Numbered { quarter, year },
+        ),
+        map(
+            (
+                parse_period_offset,
+                space1,
+                tag_no_case("quarter"),
+                not(alpha1),
+            ),
+            |(offset, ..)| Quarter::Offset(offset),
+        ),
+    ))
+    .parse(input)
+}
+
+pub fn parse_fiscal_year(input: &str) -> FlexitimeResult<&str, FiscalYear> {
+    alt((
+        map(parse_fiscal_year_number, FiscalYear::Numbered),
+        map(
+            (
+                parse_period_offset,
+                space1,
+                alt((
+                    value((), (tag_no_case("fiscal"), space1, tag_no_case("year"))),
+                    value((), tag_no_case("fy")),
+                )),
+                not(alpha1),
+            ),
+            |(offset, ..)| FiscalYear::Offset(offset),
+        ),
+    ))
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{date, today};
+
+    #[test]
+    fn test_parse_quarter() {
+        assert_eq!(
+            parse_quarter("Q3 2025"),
+            Ok((
+                "",
+                Quarter::Numbered {
+                    quarter: 3,
+                    year: Some(2025)
+                }
+            ))
+        );
+        assert_eq!(
+            parse_quarter("fy26 q1"),
+            Ok((
+                "",
+                Quarter::Numbered {
+                    quarter: 1,
+                    year: Some(2026)
+                }
+            ))
+        );
+        assert_eq!(
+            parse_quarter("q2"),
+            Ok((
+                "",
+                Quarter::Numbered {
+                    quarter: 2,
+                    year: None
+                }
+            ))
+        );
+        assert_eq!(parse_quarter("next quarter"), Ok(("", Quarter::Offset(1))));
+        assert!(parse_quarter("q12").is_err());
+        assert!(parse_quarter("fy260 q1").is_err());
+    }
+
+    #[test]
+    fn test_parse_fiscal_year() {
+        assert_eq!(
+            parse_fiscal_year("FY26"),
+            Ok(("", FiscalYear::Numbered(2026)))
+        );
+        assert_eq!(
+            parse_fiscal_year("FY 2027"),
+            Ok(("", FiscalYear::Numbered(2027)))
+        );
+        assert_eq!(
+            parse_fiscal_year("last fiscal year"),
+            Ok(("", FiscalYear::Offset(-1)))
+        );
+        assert!(parse_fiscal_year("FY260").is_err());
+        assert!(parse_fiscal_year("FY 20265").is_err());
+    }
+
+    #[test]
+    fn test_calendar_quarter_span() {
+        let options = ResolveOptions::default();
+        let today = today();
+
+        assert_eq!(
+            Quarter::Numbered {
+                quarter: 3,
+                year: Some(2025)
+            }
+            .span(today, &options),
+            Ok((date(2025, 7, 1), date(2025, 10, 1)))
+        );
+        assert_eq!(
+            Quarter::Offset(1).span(today, &options),
+            Ok((date(2026, 1, 1), date(2026, 4, 1)))
+        );
+    }
+
+    #[test]
+    fn test_fiscal_quarter_span() {
+        let options = ResolveOptions::new().fiscal_year_start(Month::October);
+        let today = today();
+
+        assert_eq!(
+            Quarter::Numbered {
+                quarter: 1,
+                year: Some(2026)
+            }
+            .span(today, &options),
+            Ok((date(2025, 10, 1), date(2026, 1, 1)))
+        );
+        assert_eq!(
+            Quarter::Numbered {
+                quarter: 2,
+                year: None
+            }
+            .resolve(today, &options),
+            Ok(date(2026, 1, 1))
+        );
+        assert_eq!(
+            Quarter::Offset(-1).resolve(today, &options),
+            Ok(date(2025, 7, 1))
+        );
+    }
+
+    #[test]
+    fn test_fiscal_year_span() {
+        let options = ResolveOptions::new().fiscal_year_start(Month::April);
+
+        assert_eq!(
+            FiscalYear::Numbered(2026).span(today(), &options),
+            Ok((date(2025, 4, 1), date(2026, 4, 1)))
+        );
+        assert_eq!(
+            FiscalYear::Offset(1).resolve(date(2025, 3, 31), &options),
+            Ok(date(2025, 4, 1))
+        );
+    }
+}
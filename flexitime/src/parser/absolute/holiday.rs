@@ -0,0 +1,184 @@
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate};
+use nom::{
+    Parser,
+    character::complete::space1,
+    combinator::opt,
+    error::{ErrorKind, ParseError},
+    sequence::preceded,
+};
+
+use super::date::parse_year;
+use crate::{
+    error::{FlexitimeError, FlexitimeResult},
+    holiday::{Holiday, HolidayCalendar},
+    resolve::{ResolveError, ResolveOptions},
+};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HolidayDate {
+    pub holiday: Holiday,
+    /// The year in which to look for the holiday. Without a year, the occurrence closest to today
+    /// in the direction of the [`Preference`](crate::resolve::Preference) is used.
+    pub year: Option<i32>,
+}
+
+impl HolidayDate {
+    pub fn new(holiday: Holiday, year: Option<i32>) -> Self {
+        Self { holiday, year }
+    }
+
+    pub fn resolve(
+        &self,
+        today: NaiveDate,
+        options: &ResolveOptions,
+    ) -> Result<NaiveDate, ResolveError> {
+        let not_observed = |year| ResolveError::HolidayNotObserved {
+            name: self.holiday.name().to_string(),
+            year,
+        };
+
+        if let Some(year) = self.year {
+            return self.holiday.rule.date_in(year).ok_or(not_observed(year));
+        }
+
+        // Allow for holidays, such as the 29th of February, which skip some years
+        options.preference.unwrap_or_default().choose(
+            today,
+            || {
+                (today.year() - 8..=today.year())
+                    .rev()
+                    .filter_map(|year| self.holiday.rule.date_in(year))
+                    .find(|date| *date <= today)
+                    .ok_or(not_observed(today.year()))
+            },
+            || {
+                (today.year()..=today.year() + 8)
+                    .filter_map(|year| self.holiday.rule.date_in(year))
+                    .find(|date| *date >= today)
+                    .ok_or(not_observed(today.year()))
+            },
+        )
+    }
+}
+
+impl fmt::Display for HolidayDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.holiday.name())?;
+        if let Some(year) = self.year {
+            write!(f, " {year}")?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_holiday_name<'a>(
+    input: &'a str,
+    calendar: &dyn HolidayCalendar,
+) -> FlexitimeResult<&'a str, Holiday> {
+    let mut longest: Option<(usize, &Holiday)> = None;
+
+    for holiday in calendar.holidays() {
+        for name in holiday.names() {
+            let matches = input
+                .get(..name.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(name))
+                && !input[name.len()..].starts_with(char::is_alphabetic);
+
+            if matches && longest.is_none_or(|(length, _)| name.len() > length) {
+                longest = Some((name.len(), holiday));
+            }
+        }
+    }
+
+    match longest {
+        Some((length, holiday)) => Ok((&input[length..], holiday.clone())),
+        None => Err(nom::Err::Error(FlexitimeError::from_error_kind(
+            input,
+            ErrorKind::Tag,
+        ))),
+    }
+}
+
+pub fn parse_holiday<'a>(
+    input: &'a str,
+    calendar: &dyn HolidayCalendar,
+) -> FlexitimeResult<&'a str, HolidayDate> {
+    let (input, holiday) = parse_holiday_name(input, calendar)?;
+    let (input, year) = opt(preceded(space1, parse_year)).parse(input)?;
+
+    Ok((input, HolidayDate::new(holiday, year.map(i32::from))))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Month;
+
+    use crate::{
+        fixtures::{date, today},
+        holiday::{HolidayRule, HolidaySet},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_parse_holiday() {
+        let calendar = HolidaySet::builtin();
+
+        let (rest, christmas) = parse_holiday("Christmas", &calendar).unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(christmas.holiday.name(), "christmas");
+        assert_eq!(christmas.year, None);
+
+        let (rest, boxing_day) = parse_holiday("boxing day 2026", &calendar).unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(boxing_day.holiday.name(), "boxing day");
+        assert_eq!(boxing_day.year, Some(2026));
+    }
+
+    #[test]
+    fn test_parse_prefers_longest_name() {
+        let calendar = HolidaySet::builtin();
+
+        let (rest, holiday) = parse_holiday("christmas eve 6pm", &calendar).unwrap();
+        assert_eq!(rest, " 6pm");
+        assert_eq!(holiday.holiday.name(), "christmas eve");
+
+        let (_, holiday) = parse_holiday("easter monday", &calendar).unwrap();
+        assert_eq!(holiday.holiday.name(), "easter monday");
+    }
+
+    #[test]
+    fn test_parse_custom_holiday() {
+        let calendar = HolidaySet::builtin().holiday(Holiday::new(
+            "founders day",
+            HolidayRule::Fixed {
+                month: Month::June,
+                day: 12,
+            },
+        ));
+
+        let (_, holiday) = parse_holiday("founders day", &calendar).unwrap();
+        assert_eq!(
+            holiday.resolve(today(), &ResolveOptions::default()),
+            Ok(date(2026, 6, 12))
+        );
+    }
+
+    #[test]
+    fn test_resolve_next_occurrence() {
+        let calendar = HolidaySet::builtin();
+        let (_, christmas) = parse_holiday("christmas", &calendar).unwrap();
+
+        assert_eq!(
+            christmas.resolve(today(), &ResolveOptions::default()),
+            Ok(date(2025, 12, 25))
+        );
+        assert_eq!(
+            christmas.resolve(date(2025, 12, 26), &ResolveOptions::default()),
+            Ok(date(2026, 12, 25))
+        );
+    }
+}
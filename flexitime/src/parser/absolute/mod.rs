@@ -1,39 +1,96 @@
 use chrono::NaiveDate;
 pub use day_offset::DayOffset;
 use nom::{
-    Parser, branch::alt, character::complete::space0, combinator::map, multi::fold_many1,
-    sequence::delimited,
+    Parser,
+    branch::alt,
+    bytes::complete::tag_no_case,
+    character::complete::{space0, space1},
+    combinator::{map, opt},
+    multi::fold_many1,
+    sequence::{delimited, preceded},
 };
 pub use wallclock_time::{TimePeriod, WallClockTime};
 
-mod day_offset;
+pub(crate) mod day_offset;
+mod fiscal;
+pub use fiscal::{FiscalYear, Quarter};
+pub(crate) mod holiday;
+pub use holiday::HolidayDate;
 mod month_offset;
 pub use month_offset::MonthOffset;
+pub(crate) mod nth_weekday;
+pub use nth_weekday::NthWeekday;
+pub(crate) mod period_boundary;
+pub use period_boundary::{Boundary, Period, PeriodBoundary, PeriodTarget};
 mod time;
+mod week;
 pub use time::{AbsoluteTime, AbsoluteTimeBuilder, FlexiDate};
+pub use week::{Week, WeekDate};
 
-use crate::error::FlexitimeResult;
+use crate::{error::FlexitimeResult, parser::FlexiParser};
 pub mod date;
 pub mod wallclock_time;
 
 pub enum AbsoluteTimePart {
+    /// A date named in the parser's vocabulary
+    NamedDate(FlexiDate),
     DayOffset(DayOffset),
     Date(NaiveDate),
     WallClockTime(WallClockTime),
     MonthOffset(MonthOffset),
+    PeriodBoundary(PeriodBoundary),
+    Quarter(Quarter),
+    FiscalYear(FiscalYear),
+    NthWeekday(NthWeekday),
+    Week(WeekDate),
+    Holiday(HolidayDate),
 }
 
 pub fn parse_absolute_time(input: &str) -> FlexitimeResult<&str, AbsoluteTime> {
+    parse_absolute_time_with(input, FlexiParser::shared())
+}
+
+pub fn parse_absolute_time_with<'a>(
+    input: &'a str,
+    parser: &FlexiParser,
+) -> FlexitimeResult<&'a str, AbsoluteTime> {
     fold_many1(
         delimited(
             space0,
             alt((
-                map(day_offset::parse_day_offset, AbsoluteTimePart::DayOffset),
                 map(
-                    wallclock_time::parse_wall_clock_time,
+                    |i: &'a str| parser.vocabulary.parse_date(i),
+                    AbsoluteTimePart::NamedDate,
+                ),
+                map(
+                    preceded(opt((tag_no_case("at"), space1)), |i: &'a str| {
+                        parser.vocabulary.parse_time(i)
+                    }),
                     AbsoluteTimePart::WallClockTime,
                 ),
+                map(
+                    period_boundary::parse_period_boundary,
+                    AbsoluteTimePart::PeriodBoundary,
+                ),
+                map(fiscal::parse_quarter, AbsoluteTimePart::Quarter),
+                map(fiscal::parse_fiscal_year, AbsoluteTimePart::FiscalYear),
+                map(nth_weekday::parse_nth_weekday, AbsoluteTimePart::NthWeekday),
+                // Weeks are tried before day offsets, which would otherwise take the weekday
+                map(week::parse_week_date, AbsoluteTimePart::Week),
+                map(
+                    |i: &'a str| holiday::parse_holiday(i, parser.holiday_calendar()),
+                    AbsoluteTimePart::Holiday,
+                ),
+                map(day_offset::parse_day_offset, AbsoluteTimePart::DayOffset),
+                // Dates are tried before times, which would otherwise take the day or year
                 map(date::parse_date, AbsoluteTimePart::Date),
+                map(
+                    preceded(
+                        opt((tag_no_case("at"), space1)),
+                        wallclock_time::parse_wall_clock_time,
+                    ),
+                    AbsoluteTimePart::WallClockTime,
+                ),
                 map(
                     month_offset::parse_month_offset,
                     AbsoluteTimePart::MonthOffset,
@@ -41,11 +98,20 @@ pub fn parse_absolute_time(input: &str) -> FlexitimeResult<&str, AbsoluteTime> {
             )),
             space0,
         ),
-        || AbsoluteTimeBuilder::new(),
+        AbsoluteTimeBuilder::new,
         |acc, part| match part {
+            AbsoluteTimePart::NamedDate(date) => acc.date(date),
             AbsoluteTimePart::DayOffset(offset) => acc.date(FlexiDate::DayOffset(offset)),
             AbsoluteTimePart::Date(date) => acc.date(FlexiDate::Date(date)),
             AbsoluteTimePart::MonthOffset(date) => acc.date(FlexiDate::MonthOffset(date)),
+            AbsoluteTimePart::PeriodBoundary(boundary) => {
+                acc.date(FlexiDate::PeriodBoundary(boundary))
+            }
+            AbsoluteTimePart::Quarter(quarter) => acc.date(FlexiDate::Quarter(quarter)),
+            AbsoluteTimePart::FiscalYear(year) => acc.date(FlexiDate::FiscalYear(year)),
+            AbsoluteTimePart::NthWeekday(nth) => acc.date(FlexiDate::NthWeekday(nth)),
+            AbsoluteTimePart::Week(week) => acc.date(FlexiDate::Week(week)),
+            AbsoluteTimePart::Holiday(holiday) => acc.date(FlexiDate::Holiday(holiday)),
             AbsoluteTimePart::WallClockTime(wall_clock_time) => acc.time(wall_clock_time),
         },
     )
@@ -68,9 +134,7 @@ mod tests {
             (
                 "",
                 AbsoluteTimeBuilder::new()
-                    .date(FlexiDate::DayOffset(DayOffset::NextDayOccurrence(
-                        Weekday::Tue
-                    )))
+                    .date(FlexiDate::DayOffset(DayOffset::Named(Weekday::Tue)))
                     .time(WallClockTime::new(9, 0, 0, Some(TimePeriod::Pm)).unwrap())
                     .build()
                     .unwrap()
@@ -93,4 +157,42 @@ mod tests {
             )
         )
     }
+
+    #[test]
+    fn test_parse_period_boundary_and_time() {
+        assert_eq!(
+            parse_absolute_time("start of next week 9am").unwrap(),
+            (
+                "",
+                AbsoluteTimeBuilder::new()
+                    .date(FlexiDate::PeriodBoundary(PeriodBoundary::new(
+                        Boundary::Start,
+                        PeriodTarget::Offset {
+                            period: Period::Week,
+                            offset: 1
+                        }
+                    )))
+                    .time(WallClockTime::new(9, 0, 0, Some(TimePeriod::Am)).unwrap())
+                    .build()
+                    .unwrap()
+            )
+        )
+    }
+
+    #[test]
+    fn test_parse_weekday_of_week_and_time() {
+        assert_eq!(
+            parse_absolute_time("friday next week at 5pm").unwrap(),
+            (
+                "",
+                AbsoluteTimeBuilder::new()
+                    .date(FlexiDate::Week(
+                        WeekDate::new(Week::Offset(1)).weekday(Weekday::Fri)
+                    ))
+                    .time(WallClockTime::new(5, 0, 0, Some(TimePeriod::Pm)).unwrap())
+                    .build()
+                    .unwrap()
+            )
+        )
+    }
 }
@@ -1,86 +1,147 @@
-use chrono::Month;
+use std::fmt;
+
+use chrono::{Datelike, Month, Months, NaiveDate};
 use nom::{
     Parser,
     branch::alt,
     bytes::complete::tag_no_case,
     character::complete::space1,
-    combinator::{opt, value},
+    combinator::{map, value},
     sequence::preceded,
 };
 
-use crate::error::FlexitimeResult;
+use super::{date::parse_year, period_boundary::write_offset};
+use crate::{
+    error::FlexitimeResult,
+    parser::Locale,
+    resolve::{ResolveError, ResolveOptions},
+};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub enum MonthOffset {
+    /// A month on its own, e.g. `december`. Which occurrence is meant depends on the
+    /// [`Preference`](crate::resolve::Preference) it is resolved with.
+    Named(Month),
+    /// The next occurrence of a month, counting the current one, e.g. `next december`
     NextMonthOccurrence(Month),
+    /// A number of months from the current one, e.g. `next month`
+    Fixed(i32),
+    /// A month of a specific year, e.g. `march 2026`
+    InYear(Month, i32),
 }
 
-pub fn parse_month_offset(input: &str) -> FlexitimeResult<&str, MonthOffset> {
-    preceded(
-        opt((alt((tag_no_case("this"), tag_no_case("next"))), space1)),
-        alt((
-            value(
-                MonthOffset::NextMonthOccurrence(Month::January),
-                alt((tag_no_case("january"), tag_no_case("jan"))),
-            ),
-            value(
-                MonthOffset::NextMonthOccurrence(Month::February),
-                alt((tag_no_case("february"), tag_no_case("feb"))),
-            ),
-            value(
-                MonthOffset::NextMonthOccurrence(Month::March),
-                alt((tag_no_case("march"), tag_no_case("mar"))),
-            ),
-            value(
-                MonthOffset::NextMonthOccurrence(Month::April),
-                alt((tag_no_case("april"), tag_no_case("apr"))),
-            ),
-            value(
-                MonthOffset::NextMonthOccurrence(Month::May),
-                tag_no_case("may"),
-            ),
-            value(
-                MonthOffset::NextMonthOccurrence(Month::June),
-                alt((tag_no_case("june"), tag_no_case("jun"))),
-            ),
-            value(
-                MonthOffset::NextMonthOccurrence(Month::July),
-                alt((tag_no_case("july"), tag_no_case("jul"))),
-            ),
-            value(
-                MonthOffset::NextMonthOccurrence(Month::August),
-                alt((tag_no_case("august"), tag_no_case("aug"))),
-            ),
-            value(
-                MonthOffset::NextMonthOccurrence(Month::September),
-                alt((tag_no_case("september"), tag_no_case("sep"))),
-            ),
-            value(
-                MonthOffset::NextMonthOccurrence(Month::October),
-                alt((tag_no_case("october"), tag_no_case("oct"))),
+impl MonthOffset {
+    /// Resolves the offset to a date. Month names keep the day of `today` where possible, whereas
+    /// months with an explicit year resolve to the first of the month.
+    pub fn resolve(
+        &self,
+        today: NaiveDate,
+        options: &ResolveOptions,
+    ) -> Result<NaiveDate, ResolveError> {
+        // The current month is both the next and the most recent occurrence of itself
+        let ahead = |month: &Month| (month.number_from_month() + 12 - today.month()) % 12;
+        // The month is named rather than counted to, so the day always stays within it whatever
+        // the month arithmetic
+        let next = |month| {
+            today
+                .checked_add_months(Months::new(ahead(month)))
+                .ok_or(ResolveError::OutOfRange)
+        };
+
+        match self {
+            MonthOffset::Named(month) => options.preference.unwrap_or_default().choose(
+                today,
+                || {
+                    today
+                        .checked_sub_months(Months::new((12 - ahead(month)) % 12))
+                        .ok_or(ResolveError::OutOfRange)
+                },
+                || next(month),
             ),
-            value(
-                MonthOffset::NextMonthOccurrence(Month::November),
-                alt((tag_no_case("november"), tag_no_case("nov"))),
+            MonthOffset::NextMonthOccurrence(month) => next(month),
+            MonthOffset::Fixed(months) => options.add_months(today, *months),
+            MonthOffset::InYear(month, year) => {
+                NaiveDate::from_ymd_opt(*year, month.number_from_month(), 1)
+                    .ok_or(ResolveError::OutOfRange)
+            }
+        }
+    }
+}
+
+impl fmt::Display for MonthOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MonthOffset::Named(month) => write!(f, "{}", month_name(*month)),
+            MonthOffset::NextMonthOccurrence(month) => write!(f, "next {}", month_name(*month)),
+            MonthOffset::Fixed(months) => write_offset(f, *months, "month"),
+            MonthOffset::InYear(month, year) => write!(f, "{} {year}", month_name(*month)),
+        }
+    }
+}
+
+pub(crate) fn month_name(month: Month) -> &'static str {
+    match month {
+        Month::January => "january",
+        Month::February => "february",
+        Month::March => "march",
+        Month::April => "april",
+        Month::May => "may",
+        Month::June => "june",
+        Month::July => "july",
+        Month::August => "august",
+        Month::September => "september",
+        Month::October => "october",
+        Month::November => "november",
+        Month::December => "december",
+    }
+}
+
+pub(crate) fn parse_month_name(input: &str) -> FlexitimeResult<&str, Month> {
+    Locale::ENGLISH.parse_month(input)
+}
+
+pub fn parse_month_offset(input: &str) -> FlexitimeResult<&str, MonthOffset> {
+    alt((
+        map(
+            (
+                alt((
+                    value(0, tag_no_case("this")),
+                    value(1, tag_no_case("next")),
+                    value(-1, tag_no_case("last")),
+                    value(-1, tag_no_case("previous")),
+                )),
+                space1,
+                tag_no_case("month"),
             ),
-            value(
-                MonthOffset::NextMonthOccurrence(Month::December),
-                alt((tag_no_case("december"), tag_no_case("dec"))),
+            |(months, _, _)| MonthOffset::Fixed(months),
+        ),
+        map(
+            (parse_month_name, space1, parse_year),
+            |(month, _, year)| MonthOffset::InYear(month, year.into()),
+        ),
+        map(
+            preceded(
+                (alt((tag_no_case("this"), tag_no_case("next"))), space1),
+                parse_month_name,
             ),
-        )),
-    )
+            MonthOffset::NextMonthOccurrence,
+        ),
+        map(parse_month_name, MonthOffset::Named),
+    ))
     .parse(input)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::resolve::MonthArithmetic;
 
     #[test]
     fn test_parse_month_offset() {
         assert_eq!(
             parse_month_offset("december"),
-            Ok(("", MonthOffset::NextMonthOccurrence(Month::December)))
+            Ok(("", MonthOffset::Named(Month::December)))
         )
     }
 
@@ -91,4 +152,75 @@ mod tests {
             Ok(("", MonthOffset::NextMonthOccurrence(Month::January)))
         )
     }
+
+    #[test]
+    fn test_parse_fixed_month_offset() {
+        assert_eq!(
+            parse_month_offset("next month"),
+            Ok(("", MonthOffset::Fixed(1)))
+        );
+        assert_eq!(
+            parse_month_offset("last month"),
+            Ok(("", MonthOffset::Fixed(-1)))
+        );
+    }
+
+    #[test]
+    fn test_parse_month_with_year() {
+        assert_eq!(
+            parse_month_offset("march 2026"),
+            Ok(("", MonthOffset::InYear(Month::March, 2026)))
+        )
+    }
+
+    #[test]
+    fn test_resolve_at_end_of_month() {
+        let today = NaiveDate::from_ymd_opt(2025, 10, 31).unwrap();
+        let arithmetics = [
+            MonthArithmetic::Clamp,
+            MonthArithmetic::Overflow,
+            MonthArithmetic::PreserveEndOfMonth,
+            MonthArithmetic::Error,
+        ];
+        let resolve = |offset: &MonthOffset, arithmetic| {
+            offset.resolve(today, &ResolveOptions::new().month_arithmetic(arithmetic))
+        };
+
+        // Month names stay within the month whatever the arithmetic
+        for arithmetic in arithmetics {
+            for (month, date) in [
+                (Month::September, (2026, 9, 30)),
+                (Month::February, (2026, 2, 28)),
+                (Month::December, (2025, 12, 31)),
+            ] {
+                assert_eq!(
+                    resolve(&MonthOffset::Named(month), arithmetic),
+                    Ok(NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap()),
+                    "{month:?} with {arithmetic:?}"
+                );
+            }
+        }
+
+        let next_month = MonthOffset::Fixed(1);
+        assert_eq!(
+            resolve(&next_month, MonthArithmetic::Clamp),
+            Ok(NaiveDate::from_ymd_opt(2025, 11, 30).unwrap())
+        );
+        assert_eq!(
+            resolve(&next_month, MonthArithmetic::Overflow),
+            Ok(NaiveDate::from_ymd_opt(2025, 12, 1).unwrap())
+        );
+        assert_eq!(
+            resolve(&next_month, MonthArithmetic::PreserveEndOfMonth),
+            Ok(NaiveDate::from_ymd_opt(2025, 11, 30).unwrap())
+        );
+        assert_eq!(
+            resolve(&next_month, MonthArithmetic::Error),
+            Err(ResolveError::NoSuchDay {
+                year: 2025,
+                month: 11,
+                day: 31
+            })
+        );
+    }
 }
@@ -0,0 +1,308 @@
+use std::fmt;
+
+use chrono::{Datelike, Months, NaiveDate, TimeDelta, Weekday};
+use nom::{
+    Parser,
+    branch::alt,
+    bytes::complete::tag_no_case,
+    character::complete::space1,
+    combinator::{map, opt, value},
+    sequence::preceded,
+};
+
+use super::{
+    day_offset::{parse_weekday, weekday_name},
+    month_offset::{MonthOffset, parse_month_offset},
+};
+use crate::{
+    error::FlexitimeResult,
+    resolve::{ResolveError, ResolveOptions},
+};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct NthWeekday {
+    /// The 1-based occurrence within the month. Negative values count back from the end of the
+    /// month, so `-1` is the last occurrence.
+    pub n: i8,
+    pub weekday: Weekday,
+    pub month: MonthOffset,
+    /// Whether to only count occurrences which are business days, e.g. `last business friday`
+    pub business: bool,
+}
+
+impl NthWeekday {
+    pub fn new(n: i8, weekday: Weekday, month: MonthOffset) -> Self {
+        Self {
+            n,
+            weekday,
+            month,
+            business: false,
+        }
+    }
+
+    pub fn business(mut self) -> Self {
+        self.business = true;
+        self
+    }
+
+    pub fn resolve(
+        &self,
+        today: NaiveDate,
+        options: &ResolveOptions,
+    ) -> Result<NaiveDate, ResolveError> {
+        let month = match self.month {
+            // Counting from the first of the month keeps within the month counted to, whatever
+            // the month arithmetic
+            MonthOffset::Fixed(_) => self
+                .month
+                .resolve(today.with_day(1).ok_or(ResolveError::OutOfRange)?, options)?,
+            _ => self.month.resolve(today, options)?,
+        };
+
+        let date = if self.business {
+            let mut occurrences = (1..=5)
+                .filter_map(|n| nth_weekday_in_month(month.year(), month.month(), n, self.weekday))
+                .filter(|date| options.is_business_day(*date))
+                .collect::<Vec<_>>();
+            if self.n < 0 {
+                occurrences.reverse();
+            }
+            occurrences
+                .get(usize::from(self.n.unsigned_abs()) - 1)
+                .copied()
+        } else {
+            nth_weekday_in_month(month.year(), month.month(), self.n, self.weekday)
+        };
+
+        date.ok_or(ResolveError::NoSuchWeekdayOccurrence {
+            n: self.n,
+            weekday: self.weekday,
+            year: month.year(),
+            month: month.month(),
+        })
+    }
+}
+
+impl fmt::Display for NthWeekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.n {
+            1 => write!(f, "first")?,
+            2 => write!(f, "second")?,
+            3 => write!(f, "third")?,
+            4 => write!(f, "fourth")?,
+            5 => write!(f, "fifth")?,
+            -1 => write!(f, "last")?,
+            -2 => write!(f, "second to last")?,
+            n if n < 0 => write!(f, "{} from last", n.unsigned_abs())?,
+            n => write!(f, "{n}th")?,
+        }
+        if self.business {
+            write!(f, " business")?;
+        }
+        write!(f, " {} of ", weekday_name(self.weekday))?;
+
+        match self.month {
+            MonthOffset::Fixed(0) => write!(f, "the month"),
+            ref month => write!(f, "{month}"),
+        }
+    }
+}
+
+/// Finds the nth occurrence of `weekday` in the given month, counting back from the end of the
+/// month when `n` is negative
+pub(crate) fn nth_weekday_in_month(
+    year: i32,
+    month: u32,
+    n: i8,
+    weekday: Weekday,
+) -> Option<NaiveDate> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+
+    let date = if n > 0 {
+        let offset = weekday.days_since(first.weekday()) + 7 * (n as u32 - 1);
+        first.checked_add_signed(TimeDelta::days(offset.into()))?
+    } else {
+        let last = first.checked_add_months(Months::new(1))?.pred_opt()?;
+        let offset = last.weekday().days_since(weekday) + 7 * (n.unsigned_abs() as u32 - 1);
+        last.checked_sub_signed(TimeDelta::days(offset.into()))?
+    };
+
+    (date.month() == month).then_some(date)
+}
+
+fn parse_ordinal(input: &str) -> FlexitimeResult<&str, i8> {
+    alt((
+        value(
+            -2,
+            alt((
+                value(
+                    (),
+                    (
+                        alt((tag_no_case("second"), tag_no_case("2nd"))),
+                        alt((space1, tag_no_case("-"))),
+                        tag_no_case("to"),
+                        alt((space1, tag_no_case("-"))),
+                        tag_no_case("last"),
+                    ),
+                ),
+                value((), tag_no_case("penultimate")),
+            )),
+        ),
+        value(1, alt((tag_no_case("first"), tag_no_case("1st")))),
+        value(2, alt((tag_no_case("second"), tag_no_case("2nd")))),
+        value(3, alt((tag_no_case("third"), tag_no_case("3rd")))),
+        value(4, alt((tag_no_case("fourth"), tag_no_case("4th")))),
+        value(5, alt((tag_no_case("fifth"), tag_no_case("5th")))),
+        value(-1, tag_no_case("last")),
+    ))
+    .parse(input)
+}
+
+fn parse_month_reference(input: &str) -> FlexitimeResult<&str, MonthOffset> {
+    alt((
+        value(
+            MonthOffset::Fixed(0),
+            (tag_no_case("the"), space1, tag_no_case("month")),
+        ),
+        parse_month_offset,
+    ))
+    .parse(input)
+}
+
+pub fn parse_nth_weekday(input: &str) -> FlexitimeResult<&str, NthWeekday> {
+    map(
+        (
+            opt((tag_no_case("the"), space1)),
+            parse_ordinal,
+            space1,
+            opt((
+                alt((tag_no_case("business"), tag_no_case("working"))),
+                space1,
+            )),
+            parse_weekday,
+            preceded(
+                (space1, alt((tag_no_case("of"), tag_no_case("in"))), space1),
+                parse_month_reference,
+            ),
+        ),
+        |(_, n, _, business, weekday, month)| {
+            let nth = NthWeekday::new(n, weekday, month);
+            if business.is_some() {
+                nth.business()
+            } else {
+                nth
+            }
+        },
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Month;
+
+    use crate::{
+        fixtures::{date, today},
+        holiday::{Holiday, HolidayRule, HolidaySet},
+        resolve::MonthArithmetic,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_parse_nth_weekday() {
+        assert_eq!(
+            parse_nth_weekday("first Monday of November"),
+            Ok((
+                "",
+                NthWeekday::new(1, Weekday::Mon, MonthOffset::Named(Month::November))
+            ))
+        );
+        assert_eq!(
+            parse_nth_weekday("the second tuesday of next month"),
+            Ok(("", NthWeekday::new(2, Weekday::Tue, MonthOffset::Fixed(1))))
+        );
+        assert_eq!(
+            parse_nth_weekday("last friday of the month"),
+            Ok(("", NthWeekday::new(-1, Weekday::Fri, MonthOffset::Fixed(0))))
+        );
+        assert_eq!(
+            parse_nth_weekday("second-to-last fri in march 2026"),
+            Ok((
+                "",
+                NthWeekday::new(-2, Weekday::Fri, MonthOffset::InYear(Month::March, 2026))
+            ))
+        );
+    }
+
+    #[test]
+    fn test_resolve_nth_weekday() {
+        let today = today();
+
+        assert_eq!(
+            NthWeekday::new(4, Weekday::Thu, MonthOffset::Named(Month::November))
+                .resolve(today, &ResolveOptions::default()),
+            Ok(date(2025, 11, 27))
+        );
+        assert_eq!(
+            NthWeekday::new(-1, Weekday::Fri, MonthOffset::Fixed(0))
+                .resolve(today, &ResolveOptions::default()),
+            Ok(date(2025, 10, 31))
+        );
+        assert_eq!(
+            NthWeekday::new(-2, Weekday::Fri, MonthOffset::InYear(Month::March, 2026))
+                .resolve(today, &ResolveOptions::default()),
+            Ok(date(2026, 3, 20))
+        );
+    }
+
+    #[test]
+    fn test_next_month_from_month_end() {
+        for arithmetic in [
+            MonthArithmetic::Clamp,
+            MonthArithmetic::Overflow,
+            MonthArithmetic::PreserveEndOfMonth,
+            MonthArithmetic::Error,
+        ] {
+            let options = ResolveOptions::new().month_arithmetic(arithmetic);
+
+            assert_eq!(
+                NthWeekday::new(2, Weekday::Tue, MonthOffset::Fixed(1))
+                    .resolve(date(2025, 10, 31), &options),
+                Ok(date(2025, 11, 11)),
+                "{arithmetic:?}"
+            );
+            assert_eq!(
+                NthWeekday::new(1, Weekday::Mon, MonthOffset::Fixed(1))
+                    .resolve(date(2025, 10, 31), &options),
+                Ok(date(2025, 11, 3)),
+                "{arithmetic:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_last_business_weekday() {
+        let options = ResolveOptions::new().holidays(
+            HolidaySet::new().holiday(Holiday::new("good friday", HolidayRule::Easter(-2))),
+        );
+
+        let (_, nth) = parse_nth_weekday("last business Friday in March 2024").unwrap();
+        assert_eq!(nth.resolve(today(), &options), Ok(date(2024, 3, 22)));
+    }
+
+    #[test]
+    fn test_missing_fifth_occurrence() {
+        assert_eq!(
+            NthWeekday::new(5, Weekday::Mon, MonthOffset::InYear(Month::February, 2026))
+                .resolve(today(), &ResolveOptions::default()),
+            Err(ResolveError::NoSuchWeekdayOccurrence {
+                n: 5,
+                weekday: Weekday::Mon,
+                year: 2026,
+                month: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,471 @@
+use std::fmt;
+
+use chrono::{Datelike, Days, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+use nom::{
+    Parser,
+    branch::alt,
+    bytes::complete::tag_no_case,
+    character::complete::{alpha1, space1},
+    combinator::{map, not, opt, value},
+    sequence::{preceded, terminated},
+};
+
+use super::{
+    date,
+    day_offset::{self, write_relative_offset},
+    fiscal::{self, fiscal_year_of, fiscal_year_start, months_into_fiscal_year},
+    month_offset,
+    time::FlexiDate,
+    week::{self, WeekDate},
+};
+use crate::{
+    error::FlexitimeResult,
+    parser::relative::{parse_relative_time, time::RelativeTime},
+    resolve::{ResolveError, ResolveOptions},
+};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Boundary {
+    Start,
+    Middle,
+    End,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Period {
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+    FiscalYear,
+}
+
+impl fmt::Display for Boundary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Boundary::Start => "start",
+            Boundary::Middle => "middle",
+            Boundary::End => "end",
+        })
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Period::Day => "day",
+            Period::Week => "week",
+            Period::Month => "month",
+            Period::Quarter => "quarter",
+            Period::Year => "year",
+            Period::FiscalYear => "fiscal year",
+        })
+    }
+}
+
+/// Writes an offset from the current period, e.g. `next week`. Offsets without a name are
+/// written relative to now, e.g. `in 3 weeks`.
+pub(crate) fn write_offset(
+    f: &mut fmt::Formatter<'_>,
+    offset: i32,
+    period: impl fmt::Display,
+) -> fmt::Result {
+    match offset {
+        0 => write!(f, "this {period}"),
+        1 => write!(f, "next {period}"),
+        -1 => write!(f, "last {period}"),
+        offset => write_relative_offset(f, offset, &period.to_string()),
+    }
+}
+
+impl Period {
+    /// Returns the half-open `[start, end)` span of the period containing `date`
+    pub fn span(
+        &self,
+        date: NaiveDate,
+        options: &ResolveOptions,
+    ) -> Result<(NaiveDate, NaiveDate), ResolveError> {
+        let start = match self {
+            Period::Day => date,
+            Period::Week => date
+                .checked_sub_days(Days::new(
+                    date.weekday().days_since(options.week_start).into(),
+                ))
+                .ok_or(ResolveError::OutOfRange)?,
+            Period::Month => date.with_day(1).ok_or(ResolveError::OutOfRange)?,
+            Period::Quarter => {
+                let months = months_into_fiscal_year(date, options.fiscal_year_start) % 3;
+                Period::Month.shift(
+                    date.with_day(1).ok_or(ResolveError::OutOfRange)?,
+                    -(months as i32),
+                )?
+            }
+            Period::Year => {
+                NaiveDate::from_ymd_opt(date.year(), 1, 1).ok_or(ResolveError::OutOfRange)?
+            }
+            Period::FiscalYear => fiscal_year_start(
+                fiscal_year_of(date, options.fiscal_year_start),
+                options.fiscal_year_start,
+            )?,
+        };
+
+        Ok((start, self.shift(start, 1)?))
+    }
+
+    /// Moves `date` forwards (or backwards, if negative) by `amount` whole periods
+    pub fn shift(&self, date: NaiveDate, amount: i32) -> Result<NaiveDate, ResolveError> {
+        let shifted = match self {
+            Period::Day => date.checked_add_signed(TimeDelta::days(amount.into())),
+            Period::Week => date.checked_add_signed(TimeDelta::weeks(amount.into())),
+            Period::Month => shift_months(date, amount),
+            Period::Quarter => shift_months(date, amount * 3),
+            Period::Year | Period::FiscalYear => shift_months(date, amount * 12),
+        };
+
+        shifted.ok_or(ResolveError::OutOfRange)
+    }
+}
+
+fn shift_months(date: NaiveDate, months: i32) -> Option<NaiveDate> {
+    if months < 0 {
+        date.checked_sub_months(Months::new(months.unsigned_abs()))
+    } else {
+        date.checked_add_months(Months::new(months.unsigned_abs()))
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum PeriodTarget {
+    /// A period counted from the one containing the anchor, e.g. `next week`
+    Offset { period: Period, offset: i32 },
+    /// The period containing a relative time, e.g. `the month in 3 months`
+    Relative { period: Period, time: RelativeTime },
+    /// The period naturally covered by a date, e.g. `tomorrow` or `december`
+    Date(Box<FlexiDate>),
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeriodBoundary {
+    pub boundary: Boundary,
+    pub target: PeriodTarget,
+}
+
+impl fmt::Display for PeriodTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PeriodTarget::Offset { period, offset } => write_offset(f, *offset, period),
+            PeriodTarget::Relative { period, time } => write!(f, "{period} {time}"),
+            PeriodTarget::Date(date) => write!(f, "{date}"),
+        }
+    }
+}
+
+impl fmt::Display for PeriodBoundary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} of {}", self.boundary, self.target)
+    }
+}
+
+impl PeriodBoundary {
+    pub fn new(boundary: Boundary, target: PeriodTarget) -> Self {
+        Self { boundary, target }
+    }
+
+    pub fn resolve(
+        &self,
+        anchor: NaiveDateTime,
+        options: &ResolveOptions,
+    ) -> Result<NaiveDateTime, ResolveError> {
+        let (start, end) = match &self.target {
+            PeriodTarget::Offset { period, offset } => {
+                let (start, _) = period.span(anchor.date(), options)?;
+                period.span(period.shift(start, *offset)?, options)?
+            }
+            PeriodTarget::Relative { period, time } => {
+                period.span(time.resolve(anchor, options)?.date(), options)?
+            }
+            PeriodTarget::Date(date) => date
+                .period()
+                .span(date.resolve(anchor, options)?.date(), options)?,
+        };
+
+        let start = start.and_time(NaiveTime::MIN);
+        let end = end.and_time(NaiveTime::MIN);
+
+        Ok(match self.boundary {
+            Boundary::Start => start,
+            Boundary::Middle => start + (end - start) / 2,
+            Boundary::End => end - TimeDelta::seconds(1),
+        })
+    }
+}
+
+fn parse_boundary(input: &str) -> FlexitimeResult<&str, Boundary> {
+    alt((
+        value(
+            Boundary::Start,
+            alt((tag_no_case("start"), tag_no_case("beginning"))),
+        ),
+        value(
+            Boundary::Middle,
+            alt((tag_no_case("middle"), tag_no_case("mid"))),
+        ),
+        value(Boundary::End, tag_no_case("end")),
+    ))
+    .parse(input)
+}
+
+pub(crate) fn parse_period(input: &str) -> FlexitimeResult<&str, Period> {
+    terminated(
+        alt((
+            value(Period::Day, tag_no_case("day")),
+            value(Period::Week, tag_no_case("week")),
+            value(Period::Month, tag_no_case("month")),
+            value(Period::Quarter, tag_no_case("quarter")),
+            value(
+                Period::FiscalYear,
+                (tag_no_case("fiscal"), space1, tag_no_case("year")),
+            ),
+            value(Period::Year, tag_no_case("year")),
+        )),
+        not(alpha1),
+    )
+    .parse(input)
+}
+
+pub(crate) fn parse_period_offset(input: &str) -> FlexitimeResult<&str, i32> {
+    alt((
+        value(0, tag_no_case("this")),
+        value(1, tag_no_case("next")),
+        value(-1, tag_no_case("last")),
+        value(-1, tag_no_case("previous")),
+    ))
+    .parse(input)
+}
+
+fn parse_period_target(input: &str) -> FlexitimeResult<&str, PeriodTarget> {
+    alt((
+        map(week::parse_numbered_week, |week| {
+            PeriodTarget::Date(Box::new(FlexiDate::Week(WeekDate::new(week))))
+        }),
+        map(
+            (parse_period_offset, space1, parse_period),
+            |(offset, _, period)| PeriodTarget::Offset { period, offset },
+        ),
+        map(
+            (parse_period, space1, parse_relative_time),
+            |(period, _, time)| PeriodTarget::Relative { period, time },
+        ),
+        map(parse_period, |period| PeriodTarget::Offset {
+            period,
+            offset: 0,
+        }),
+        map(fiscal::parse_quarter, |quarter| {
+            PeriodTarget::Date(Box::new(FlexiDate::Quarter(quarter)))
+        }),
+        map(fiscal::parse_fiscal_year, |year| {
+            PeriodTarget::Date(Box::new(FlexiDate::FiscalYear(year)))
+        }),
+        map(day_offset::parse_day_offset, |offset| {
+            PeriodTarget::Date(Box::new(FlexiDate::DayOffset(offset)))
+        }),
+        map(month_offset::parse_month_offset, |offset| {
+            PeriodTarget::Date(Box::new(FlexiDate::MonthOffset(offset)))
+        }),
+        map(date::parse_date, |date| {
+            PeriodTarget::Date(Box::new(FlexiDate::Date(date)))
+        }),
+    ))
+    .parse(input)
+}
+
+pub fn parse_period_boundary(input: &str) -> FlexitimeResult<&str, PeriodBoundary> {
+    map(
+        (
+            terminated(parse_boundary, space1),
+            preceded(
+                (tag_no_case("of"), space1, opt((tag_no_case("the"), space1))),
+                parse_period_target,
+            ),
+        ),
+        |(boundary, target)| PeriodBoundary::new(boundary, target),
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Month, Weekday};
+
+    use crate::{
+        fixtures::{anchor, date, datetime},
+        parser::absolute::{DayOffset, MonthOffset},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_parse_period_offset() {
+        assert_eq!(
+            parse_period_boundary("start of next week"),
+            Ok((
+                "",
+                PeriodBoundary::new(
+                    Boundary::Start,
+                    PeriodTarget::Offset {
+                        period: Period::Week,
+                        offset: 1
+                    }
+                )
+            ))
+        );
+        assert_eq!(
+            parse_period_boundary("end of the month"),
+            Ok((
+                "",
+                PeriodBoundary::new(
+                    Boundary::End,
+                    PeriodTarget::Offset {
+                        period: Period::Month,
+                        offset: 0
+                    }
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_period_requires_word_boundary() {
+        assert!(parse_period_boundary("end of the weekend").is_err());
+        assert!(parse_period_boundary("start of next weeks").is_err());
+        assert!(parse_period_boundary("end of the daylight").is_err());
+    }
+
+    #[test]
+    fn test_parse_date_targets() {
+        assert_eq!(
+            parse_period_boundary("end of tomorrow"),
+            Ok((
+                "",
+                PeriodBoundary::new(
+                    Boundary::End,
+                    PeriodTarget::Date(Box::new(FlexiDate::DayOffset(DayOffset::Fixed(1))))
+                )
+            ))
+        );
+        assert_eq!(
+            parse_period_boundary("beginning of december"),
+            Ok((
+                "",
+                PeriodBoundary::new(
+                    Boundary::Start,
+                    PeriodTarget::Date(Box::new(FlexiDate::MonthOffset(MonthOffset::Named(
+                        Month::December
+                    ))))
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_relative_target() {
+        assert_eq!(
+            parse_period_boundary("middle of the month in 2 months"),
+            Ok((
+                "",
+                PeriodBoundary::new(
+                    Boundary::Middle,
+                    PeriodTarget::Relative {
+                        period: Period::Month,
+                        time: RelativeTime::new().months(2)
+                    }
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_resolve_week_boundaries() {
+        let (_, boundary) = parse_period_boundary("start of this week").unwrap();
+        assert_eq!(
+            boundary.resolve(anchor(), &ResolveOptions::default()),
+            Ok(datetime(2025, 10, 20, 0, 0))
+        );
+        assert_eq!(
+            boundary.resolve(anchor(), &ResolveOptions::new().week_start(Weekday::Sun)),
+            Ok(datetime(2025, 10, 19, 0, 0))
+        );
+
+        let (_, boundary) = parse_period_boundary("end of next week").unwrap();
+        assert_eq!(
+            boundary.resolve(anchor(), &ResolveOptions::default()),
+            Ok(date(2025, 11, 2).and_hms_opt(23, 59, 59).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_resolve_month_quarter_year_boundaries() {
+        let options = ResolveOptions::default();
+
+        let (_, boundary) = parse_period_boundary("end of last month").unwrap();
+        assert_eq!(
+            boundary.resolve(anchor(), &options),
+            Ok(date(2025, 9, 30).and_hms_opt(23, 59, 59).unwrap())
+        );
+
+        let (_, boundary) = parse_period_boundary("start of next quarter").unwrap();
+        assert_eq!(
+            boundary.resolve(anchor(), &options),
+            Ok(datetime(2026, 1, 1, 0, 0))
+        );
+
+        let (_, boundary) = parse_period_boundary("beginning of the year").unwrap();
+        assert_eq!(
+            boundary.resolve(anchor(), &options),
+            Ok(datetime(2025, 1, 1, 0, 0))
+        );
+
+        let (_, boundary) = parse_period_boundary("middle of the day").unwrap();
+        assert_eq!(
+            boundary.resolve(anchor(), &options),
+            Ok(datetime(2025, 10, 22, 12, 0))
+        );
+    }
+
+    #[test]
+    fn test_resolve_fiscal_boundaries() {
+        let options = ResolveOptions::new().fiscal_year_start(Month::October);
+
+        let (_, boundary) = parse_period_boundary("beginning of Q3").unwrap();
+        assert_eq!(
+            boundary.resolve(anchor(), &options),
+            Ok(datetime(2026, 4, 1, 0, 0))
+        );
+
+        let (_, boundary) = parse_period_boundary("end of this quarter").unwrap();
+        assert_eq!(
+            boundary.resolve(anchor(), &options),
+            Ok(date(2025, 12, 31).and_hms_opt(23, 59, 59).unwrap())
+        );
+
+        let (_, boundary) = parse_period_boundary("end of FY25").unwrap();
+        assert_eq!(
+            boundary.resolve(anchor(), &options),
+            Ok(date(2025, 9, 30).and_hms_opt(23, 59, 59).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_resolve_date_target() {
+        let (_, boundary) = parse_period_boundary("end of december").unwrap();
+        assert_eq!(
+            boundary.resolve(anchor(), &ResolveOptions::default()),
+            Ok(date(2025, 12, 31).and_hms_opt(23, 59, 59).unwrap())
+        );
+    }
+}
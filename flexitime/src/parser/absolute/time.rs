@@ -1,58 +1,185 @@
-use chrono::{Datelike, Days, Months, NaiveDate, NaiveDateTime, Utc};
+use std::fmt;
 
-use super::{day_offset::DayOffset, wallclock_time::WallClockTime, month_offset::MonthOffset};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, TimeZone, Utc};
 
+use super::{
+    day_offset::DayOffset,
+    fiscal::{FiscalYear, Quarter},
+    holiday::HolidayDate,
+    month_offset::MonthOffset,
+    nth_weekday::NthWeekday,
+    period_boundary::{Period, PeriodBoundary},
+    wallclock_time::WallClockTime,
+    week::WeekDate,
+};
+use crate::resolve::{ResolveError, ResolveOptions};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, PartialEq, Clone)]
 pub enum FlexiDate {
     Date(NaiveDate),
     DayOffset(DayOffset),
-    MonthOffset(MonthOffset)
+    MonthOffset(MonthOffset),
+    PeriodBoundary(PeriodBoundary),
+    Quarter(Quarter),
+    FiscalYear(FiscalYear),
+    NthWeekday(NthWeekday),
+    Week(WeekDate),
+    Holiday(HolidayDate),
+}
+
+impl FlexiDate {
+    /// Resolves the date relative to `anchor`. Plain dates resolve to midnight, whereas
+    /// period boundaries resolve to an exact instant.
+    pub fn resolve(
+        &self,
+        anchor: NaiveDateTime,
+        options: &ResolveOptions,
+    ) -> Result<NaiveDateTime, ResolveError> {
+        let today = anchor.date();
+
+        let date = match self {
+            FlexiDate::Date(date) => *date,
+            FlexiDate::DayOffset(offset) => offset.resolve(today, options)?,
+            FlexiDate::MonthOffset(offset) => offset.resolve(today, options)?,
+            FlexiDate::Quarter(quarter) => quarter.resolve(today, options)?,
+            FlexiDate::FiscalYear(year) => year.resolve(today, options)?,
+            FlexiDate::NthWeekday(nth) => nth.resolve(today, options)?,
+            FlexiDate::Week(week) => week.resolve(today, options)?,
+            FlexiDate::Holiday(holiday) => holiday.resolve(today, options)?,
+            FlexiDate::PeriodBoundary(boundary) => return boundary.resolve(anchor, options),
+        };
+
+        Ok(date.and_time(NaiveTime::MIN))
+    }
+
+    /// The period of time naturally covered by this date
+    pub fn period(&self) -> Period {
+        match self {
+            FlexiDate::MonthOffset(_) => Period::Month,
+            FlexiDate::Quarter(_) => Period::Quarter,
+            FlexiDate::FiscalYear(_) => Period::FiscalYear,
+            FlexiDate::Week(WeekDate { weekday: None, .. }) => Period::Week,
+            FlexiDate::Date(_)
+            | FlexiDate::DayOffset(_)
+            | FlexiDate::NthWeekday(_)
+            | FlexiDate::Week(_)
+            | FlexiDate::Holiday(_)
+            | FlexiDate::PeriodBoundary(_) => Period::Day,
+        }
+    }
+}
+
+impl fmt::Display for FlexiDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FlexiDate::Date(date) => write!(f, "{}", date.format("%Y-%m-%d")),
+            FlexiDate::DayOffset(offset) => write!(f, "{offset}"),
+            FlexiDate::MonthOffset(offset) => write!(f, "{offset}"),
+            FlexiDate::PeriodBoundary(boundary) => write!(f, "{boundary}"),
+            FlexiDate::Quarter(quarter) => write!(f, "{quarter}"),
+            FlexiDate::FiscalYear(year) => write!(f, "{year}"),
+            FlexiDate::NthWeekday(nth) => write!(f, "{nth}"),
+            FlexiDate::Week(week) => write!(f, "{week}"),
+            FlexiDate::Holiday(holiday) => write!(f, "{holiday}"),
+        }
+    }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, PartialEq, Clone)]
 pub struct AbsoluteTime {
-    time: WallClockTime,
-    date: FlexiDate,
+    time: Option<WallClockTime>,
+    date: Option<FlexiDate>,
 }
 
 impl AbsoluteTime {
     pub fn new(time: WallClockTime, date: FlexiDate) -> Self {
-        Self { time, date }
-    }
-
-    pub fn to_chrono(&self) -> NaiveDateTime {
-        let mut datetime = Utc::now()
-            .naive_utc()
-            .date()
-            .and_time(self.time.to_naive_time());
-
-        datetime = match &self.date {
-            FlexiDate::Date(date) => date.and_time(datetime.time()),
-            FlexiDate::DayOffset(DayOffset::Fixed(days)) => {
-                if *days < 0 {
-                    datetime.checked_sub_days(Days::new(-days as u64)).unwrap()
-                } else {
-                    datetime.checked_add_days(Days::new(*days as u64)).unwrap()
-                }
-            }
-            FlexiDate::DayOffset(DayOffset::NextDayOccurrence(weekday)) => {
-                let current_day = datetime.weekday();
-                let offset = 7 - current_day.days_since(weekday.clone());
-                datetime.checked_add_days(Days::new(offset.into())).unwrap()
-            },
-            FlexiDate::MonthOffset(MonthOffset::NextMonthOccurrence(month)) => {
-                while datetime.month0() != (*month as u32) {
-                    datetime = datetime.checked_add_months(Months::new(1)).unwrap();
-                }
-                datetime
+        Self {
+            time: Some(time),
+            date: Some(date),
+        }
+    }
+
+    pub fn time(&self) -> Option<&WallClockTime> {
+        self.time.as_ref()
+    }
+
+    pub fn date(&self) -> Option<&FlexiDate> {
+        self.date.as_ref()
+    }
+
+    /// Resolves the time relative to `anchor`. A missing date is taken to be the anchor's date
+    /// and a missing time is taken from the date, which is midnight unless it is a period
+    /// boundary.
+    pub fn resolve(
+        &self,
+        anchor: NaiveDateTime,
+        options: &ResolveOptions,
+    ) -> Result<NaiveDateTime, ResolveError> {
+        let datetime = match (&self.date, &self.time) {
+            (Some(date), _) => date.resolve(anchor, options)?,
+            // A time of day on its own may fall on the day before or after the anchor
+            (None, Some(time)) => {
+                let on = |days| {
+                    anchor
+                        .date()
+                        .checked_add_signed(TimeDelta::days(days))
+                        .map(|date| date.and_time(time.to_naive_time()))
+                        .ok_or(ResolveError::OutOfRange)
+                };
+                let today = on(0)?;
+                let Some(preference) = options.preference else {
+                    return Ok(today);
+                };
+
+                return preference.choose(
+                    anchor,
+                    || if today <= anchor { Ok(today) } else { on(-1) },
+                    || if today >= anchor { Ok(today) } else { on(1) },
+                );
             }
+            (None, None) => anchor.date().and_time(NaiveTime::MIN),
         };
 
-        datetime
+        Ok(match &self.time {
+            Some(time) => datetime.date().and_time(time.to_naive_time()),
+            None => datetime,
+        })
+    }
+
+    /// Resolves the time relative to `anchor` in its time zone, following the gap and fold
+    /// policies when the local time is skipped or repeated by a change of the clocks
+    pub fn resolve_in<Tz: TimeZone>(
+        &self,
+        anchor: &DateTime<Tz>,
+        options: &ResolveOptions,
+    ) -> Result<DateTime<Tz>, ResolveError> {
+        let local = self.resolve(anchor.naive_local(), options)?;
+        options.localize(&anchor.timezone(), local)
+    }
+
+    /// Resolves the time against the current time with the default [`ResolveOptions`], so
+    /// weeks start on Monday as in ISO 8601
+    pub fn to_chrono(&self) -> Result<NaiveDateTime, ResolveError> {
+        self.resolve(Utc::now().naive_utc(), &ResolveOptions::default())
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+impl fmt::Display for AbsoluteTime {
+    /// Writes the time in a canonical form which parses back to an equal value, e.g.
+    /// `friday at 21:00`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.date, &self.time) {
+            (Some(date), Some(time)) => write!(f, "{date} at {time}"),
+            (Some(date), None) => write!(f, "{date}"),
+            (None, Some(time)) => write!(f, "{time}"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
 pub struct AbsoluteTimeBuilder {
     time: Option<WallClockTime>,
     date: Option<FlexiDate>,
@@ -78,8 +205,127 @@ impl AbsoluteTimeBuilder {
 
     pub fn build(self) -> Result<AbsoluteTime, String> {
         match (self.time, self.date) {
-            (Some(time), Some(date)) => Ok(AbsoluteTime { time, date }),
-            _ => Err("Missing required fields".to_string()),
+            (None, None) => Err("Missing required fields".to_string()),
+            (time, date) => Ok(AbsoluteTime { time, date }),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::Weekday;
+
+    use super::*;
+    use crate::{fixtures::anchor, resolve::Preference};
+
+    #[test]
+    fn test_resolve_date_and_time() {
+        let time = AbsoluteTimeBuilder::new()
+            .date(FlexiDate::DayOffset(DayOffset::NextDayOccurrence(
+                Weekday::Fri,
+            )))
+            .time(WallClockTime::new(9, 0, 0, None).unwrap())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            time.resolve(anchor(), &ResolveOptions::default()),
+            Ok(NaiveDate::from_ymd_opt(2025, 10, 24)
+                .unwrap()
+                .and_hms_opt(9, 0, 0)
+                .unwrap())
+        );
+    }
+
+    #[test]
+    fn test_resolve_missing_parts() {
+        let time_only = AbsoluteTimeBuilder::new()
+            .time(WallClockTime::new(9, 0, 0, None).unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(
+            time_only.resolve(anchor(), &ResolveOptions::default()),
+            Ok(anchor().date().and_hms_opt(9, 0, 0).unwrap())
+        );
+
+        let date_only = AbsoluteTimeBuilder::new()
+            .date(FlexiDate::DayOffset(DayOffset::Fixed(1)))
+            .build()
+            .unwrap();
+        assert_eq!(
+            date_only.resolve(anchor(), &ResolveOptions::default()),
+            Ok(NaiveDate::from_ymd_opt(2025, 10, 23)
+                .unwrap()
+                .and_time(NaiveTime::MIN))
+        );
+    }
+
+    #[test]
+    fn test_resolve_with_preference() {
+        let resolve = |input: &str, preference| {
+            let (_, time) = crate::parser::parse_timestring(input).unwrap();
+            time.resolve(anchor(), &ResolveOptions::new().prefer(preference))
+                .unwrap()
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        };
+        let cases = [
+            (
+                "9am",
+                ["2025-10-23 09:00", "2025-10-22 09:00", "2025-10-22 09:00"],
+            ),
+            (
+                "2am",
+                ["2025-10-23 02:00", "2025-10-22 02:00", "2025-10-23 02:00"],
+            ),
+            (
+                "11pm",
+                ["2025-10-22 23:00", "2025-10-21 23:00", "2025-10-22 23:00"],
+            ),
+            (
+                "friday",
+                ["2025-10-24 00:00", "2025-10-17 00:00", "2025-10-24 00:00"],
+            ),
+            (
+                "monday 9am",
+                ["2025-10-27 09:00", "2025-10-20 09:00", "2025-10-20 09:00"],
+            ),
+            (
+                "december",
+                ["2025-12-22 00:00", "2024-12-22 00:00", "2025-12-22 00:00"],
+            ),
+            (
+                "may",
+                ["2026-05-22 00:00", "2025-05-22 00:00", "2025-05-22 00:00"],
+            ),
+            (
+                "christmas",
+                ["2025-12-25 00:00", "2024-12-25 00:00", "2025-12-25 00:00"],
+            ),
+            (
+                "last friday",
+                ["2025-10-17 00:00", "2025-10-17 00:00", "2025-10-17 00:00"],
+            ),
+            // Asking for the next occurrence looks forward whatever the preference
+            (
+                "next friday",
+                ["2025-10-24 00:00", "2025-10-24 00:00", "2025-10-24 00:00"],
+            ),
+            (
+                "next december",
+                ["2025-12-22 00:00", "2025-12-22 00:00", "2025-12-22 00:00"],
+            ),
+        ];
+
+        for (input, [future, past, nearest]) in cases {
+            assert_eq!(resolve(input, Preference::Future), future, "{input}");
+            assert_eq!(resolve(input, Preference::Past), past, "{input}");
+            assert_eq!(resolve(input, Preference::Nearest), nearest, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_build_empty() {
+        assert!(AbsoluteTimeBuilder::new().build().is_err());
+    }
+}
@@ -1,4 +1,6 @@
-use chrono::NaiveTime;
+use std::fmt;
+
+use chrono::{NaiveTime, Timelike};
 use nom::{
     Parser,
     branch::alt,
@@ -10,6 +12,7 @@ use nom::{
 
 use crate::error::FlexitimeResult;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(PartialEq, Debug, Clone)]
 pub struct WallClockTime {
     pub hour: u8,
@@ -23,6 +26,28 @@ impl WallClockTime {
     }
 }
 
+impl From<NaiveTime> for WallClockTime {
+    fn from(time: NaiveTime) -> Self {
+        // Each component of a NaiveTime is less than 60, so always fits
+        Self {
+            hour: time.hour() as u8,
+            minute: time.minute() as u8,
+            second: time.second() as u8,
+        }
+    }
+}
+
+impl fmt::Display for WallClockTime {
+    /// Writes the time in 24-hour form, e.g. `09:30` or `21:15:05`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)?;
+        if self.second != 0 {
+            write!(f, ":{:02}", self.second)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(PartialEq, Debug, thiserror::Error)]
 pub enum WallClockTimeError {
     #[error("Hours value '{0}' is out of range in a 24-hour format")]
@@ -47,7 +72,7 @@ impl WallClockTime {
     ) -> Result<Self, WallClockTimeError> {
         if hour > 23 && period.is_none() {
             return Err(WallClockTimeError::OutOfRangeHours24(hour));
-        } else if (hour < 1 || hour > 12) && period.is_some() {
+        } else if !(1..=12).contains(&hour) && period.is_some() {
             return Err(WallClockTimeError::OutOfRangeHours12(hour));
         }
 
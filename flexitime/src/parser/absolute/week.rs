@@ -0,0 +1,307 @@
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate, TimeDelta, Weekday};
+use nom::{
+    Parser,
+    branch::alt,
+    bytes::complete::tag_no_case,
+    character::complete::{digit1, space1},
+    combinator::{map, map_opt, opt},
+    sequence::preceded,
+};
+
+use super::{
+    date::parse_year,
+    day_offset::{parse_weekday, weekday_name},
+    period_boundary::{Period, parse_period_offset, write_offset},
+};
+use crate::{
+    error::FlexitimeResult,
+    resolve::{ResolveError, ResolveOptions},
+};
+
+/// The first day of week 1 of `year`. Weeks starting on Monday are numbered as in ISO 8601,
+/// where week 1 contains the year's first Thursday. Otherwise week 1 is the week containing
+/// 1 January.
+pub fn week_year_start(year: i32, week_start: Weekday) -> Result<NaiveDate, ResolveError> {
+    let date = if week_start == Weekday::Mon {
+        NaiveDate::from_isoywd_opt(year, 1, Weekday::Mon)
+    } else {
+        NaiveDate::from_ymd_opt(year, 1, 1).and_then(|first| {
+            first.checked_sub_signed(TimeDelta::days(
+                first.weekday().days_since(week_start).into(),
+            ))
+        })
+    };
+
+    date.ok_or(ResolveError::OutOfRange)
+}
+
+/// The year whose weeks `date` is counted in, which differs from its calendar year for the days
+/// around 1 January
+pub fn week_year_of(date: NaiveDate, week_start: Weekday) -> Result<i32, ResolveError> {
+    let year = date.year();
+
+    Ok(if date >= week_year_start(year + 1, week_start)? {
+        year + 1
+    } else if date < week_year_start(year, week_start)? {
+        year - 1
+    } else {
+        year
+    })
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Week {
+    /// A numbered week of a year, e.g. `week 43` or `week 43 2025`. Without a year, the year
+    /// of the current week is used.
+    Numbered { week: u8, year: Option<i32> },
+    /// A week counted from the current one, e.g. `next week`
+    Offset(i32),
+}
+
+impl fmt::Display for Week {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Week::Numbered {
+                week,
+                year: Some(year),
+            } => write!(f, "week {week} {year}"),
+            Week::Numbered { week, year: None } => write!(f, "week {week}"),
+            Week::Offset(offset) => write_offset(f, *offset, Period::Week),
+        }
+    }
+}
+
+impl Week {
+    /// Returns the half-open `[start, end)` span of the week
+    pub fn span(
+        &self,
+        today: NaiveDate,
+        options: &ResolveOptions,
+    ) -> Result<(NaiveDate, NaiveDate), ResolveError> {
+        let start = match self {
+            Week::Numbered { week, year } => {
+                let year = match year {
+                    Some(year) => *year,
+                    None => week_year_of(today, options.week_start)?,
+                };
+                let start = Period::Week.shift(
+                    week_year_start(year, options.week_start)?,
+                    i32::from(*week) - 1,
+                )?;
+
+                if *week == 0 || start >= week_year_start(year + 1, options.week_start)? {
+                    return Err(ResolveError::NoSuchWeek { week: *week, year });
+                }
+                start
+            }
+            Week::Offset(offset) => {
+                let (current, _) = Period::Week.span(today, options)?;
+                Period::Week.shift(current, *offset)?
+            }
+        };
+
+        Period::Week.span(start, options)
+    }
+}
+
+/// A week, or a day within it, e.g. `next week` or `friday of week 43`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeekDate {
+    pub week: Week,
+    /// The day within the week. Without one, the week resolves to its first day.
+    pub weekday: Option<Weekday>,
+}
+
+impl WeekDate {
+    pub fn new(week: Week) -> Self {
+        Self {
+            week,
+            weekday: None,
+        }
+    }
+
+    pub fn weekday(mut self, weekday: Weekday) -> Self {
+        self.weekday = Some(weekday);
+        self
+    }
+
+    pub fn resolve(
+        &self,
+        today: NaiveDate,
+        options: &ResolveOptions,
+    ) -> Result<NaiveDate, ResolveError> {
+        let (start, _) = self.week.span(today, options)?;
+
+        match self.weekday {
+            Some(weekday) => start
+                .checked_add_signed(TimeDelta::days(
+                    weekday.days_since(options.week_start).into(),
+                ))
+                .ok_or(ResolveError::OutOfRange),
+            None => Ok(start),
+        }
+    }
+}
+
+impl fmt::Display for WeekDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(weekday) = self.weekday {
+            write!(f, "{} of ", weekday_name(weekday))?;
+        }
+        write!(f, "{}", self.week)
+    }
+}
+
+pub(crate) fn parse_numbered_week(input: &str) -> FlexitimeResult<&str, Week> {
+    map(
+        (
+            tag_no_case("week"),
+            space1,
+            map_opt(digit1, |s: &str| {
+                s.parse::<u8>().ok().filter(|week| (1..=53).contains(week))
+            }),
+            opt(preceded(
+                (space1, opt((tag_no_case("of"), space1))),
+                parse_year,
+            )),
+        ),
+        |(_, _, week, year)| Week::Numbered {
+            week,
+            year: year.map(i32::from),
+        },
+    )
+    .parse(input)
+}
+
+fn parse_week(input: &str) -> FlexitimeResult<&str, Week> {
+    alt((
+        map(
+            (parse_period_offset, space1, tag_no_case("week")),
+            |(offset, _, _)| Week::Offset(offset),
+        ),
+        parse_numbered_week,
+    ))
+    .parse(input)
+}
+
+pub fn parse_week_date(input: &str) -> FlexitimeResult<&str, WeekDate> {
+    alt((
+        map(
+            (
+                parse_weekday,
+                space1,
+                opt((alt((tag_no_case("of"), tag_no_case("in"))), space1)),
+                parse_week,
+            ),
+            |(weekday, _, _, week)| WeekDate::new(week).weekday(weekday),
+        ),
+        map(parse_week, WeekDate::new),
+    ))
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{date, today};
+
+    fn resolve(input: &str, week_start: Weekday) -> Result<NaiveDate, ResolveError> {
+        let (rest, week) = parse_week_date(input).unwrap();
+        assert_eq!(rest, "", "{input}");
+        week.resolve(today(), &ResolveOptions::new().week_start(week_start))
+    }
+
+    #[test]
+    fn test_parse_week_date() {
+        assert_eq!(
+            parse_week_date("next week"),
+            Ok(("", WeekDate::new(Week::Offset(1))))
+        );
+        assert_eq!(
+            parse_week_date("friday of last week"),
+            Ok(("", WeekDate::new(Week::Offset(-1)).weekday(Weekday::Fri)))
+        );
+        assert_eq!(
+            parse_week_date("week 43 2025"),
+            Ok((
+                "",
+                WeekDate::new(Week::Numbered {
+                    week: 43,
+                    year: Some(2025)
+                })
+            ))
+        );
+        assert_eq!(
+            parse_week_date("monday week 2"),
+            Ok((
+                "",
+                WeekDate::new(Week::Numbered {
+                    week: 2,
+                    year: None
+                })
+                .weekday(Weekday::Mon)
+            ))
+        );
+        assert!(parse_week_date("week 54").is_err());
+        assert!(parse_week_date("week 0").is_err());
+    }
+
+    #[test]
+    fn test_resolve_offset_weeks() {
+        assert_eq!(resolve("this week", Weekday::Mon), Ok(date(2025, 10, 20)));
+        assert_eq!(resolve("this week", Weekday::Sun), Ok(date(2025, 10, 19)));
+        assert_eq!(resolve("this week", Weekday::Sat), Ok(date(2025, 10, 18)));
+        assert_eq!(resolve("next week", Weekday::Mon), Ok(date(2025, 10, 27)));
+        assert_eq!(resolve("last week", Weekday::Sun), Ok(date(2025, 10, 12)));
+
+        assert_eq!(
+            resolve("friday next week", Weekday::Mon),
+            Ok(date(2025, 10, 31))
+        );
+        assert_eq!(
+            resolve("sunday this week", Weekday::Mon),
+            Ok(date(2025, 10, 26))
+        );
+        assert_eq!(
+            resolve("sunday this week", Weekday::Sun),
+            Ok(date(2025, 10, 19))
+        );
+    }
+
+    #[test]
+    fn test_resolve_numbered_weeks() {
+        assert_eq!(resolve("week 43", Weekday::Mon), Ok(date(2025, 10, 20)));
+        assert_eq!(resolve("week 1 2026", Weekday::Mon), Ok(date(2025, 12, 29)));
+        assert_eq!(resolve("week 1 2026", Weekday::Sun), Ok(date(2025, 12, 28)));
+        assert_eq!(resolve("week 43", Weekday::Sun), Ok(date(2025, 10, 19)));
+        assert_eq!(
+            resolve("week 53 2026", Weekday::Mon),
+            Ok(date(2026, 12, 28))
+        );
+        assert_eq!(
+            resolve("week 53 2025", Weekday::Mon),
+            Err(ResolveError::NoSuchWeek {
+                week: 53,
+                year: 2025
+            })
+        );
+    }
+
+    #[test]
+    fn test_week_year_of() {
+        assert_eq!(week_year_of(date(2025, 12, 30), Weekday::Mon), Ok(2026));
+        assert_eq!(week_year_of(date(2021, 1, 2), Weekday::Mon), Ok(2020));
+        assert_eq!(week_year_of(date(2021, 1, 2), Weekday::Sun), Ok(2021));
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for input in ["next week", "friday of last week", "week 43", "week 1 2026"] {
+            assert_eq!(parse_week_date(input).unwrap().1.to_string(), input);
+        }
+    }
+}
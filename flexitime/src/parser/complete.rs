@@ -0,0 +1,466 @@
+use std::collections::HashSet;
+
+use super::{FlexiParser, Locale, ParsedTime, absolute::wallclock_time::parse_wall_clock_time};
+
+/// A place in the shape of an expression
+#[derive(Debug, Clone)]
+enum Slot {
+    /// One of a list of words or phrases, such as the names of the weekdays
+    Words(Vec<String>),
+    Number,
+    /// A time of day, or one of the given names for one
+    Time(Vec<String>),
+}
+
+/// Where the typed words have reached within a template
+#[derive(Debug, Clone, Copy)]
+struct Position<'t> {
+    slot: usize,
+    /// A phrase which has been partly typed, and the number of its words typed so far
+    within: Option<(&'t str, usize)>,
+}
+
+/// A way of continuing a partially typed expression
+#[derive(Debug, Clone, PartialEq)]
+pub struct Completion {
+    /// The typed text with its last word completed or another word added, e.g. `next wednesday`
+    /// for `next we`
+    pub text: String,
+    /// What the completed text parses as, or `None` if it is only the start of an expression
+    pub time: Option<ParsedTime>,
+}
+
+/// The ways of continuing a partially typed expression, as offered by a search box
+///
+/// ```
+/// use flexitime::{ResolveOptions, parser::complete};
+///
+/// let completions = complete("next we");
+/// assert!(completions.valid_prefix);
+/// assert_eq!(completions.candidates[0].text, "next wednesday");
+///
+/// // Whole expressions can be resolved to show an example of what they mean
+/// let anchor = chrono::Local::now().naive_local();
+/// let example = completions.candidates[0]
+///     .time
+///     .as_ref()
+///     .map(|time| time.resolve(anchor, &ResolveOptions::default()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Completions {
+    /// Whether the typed text is a whole expression, or the start of one
+    pub valid_prefix: bool,
+    /// Continuations, with whole expressions ranked before those needing more words
+    pub candidates: Vec<Completion>,
+}
+
+fn words<'a>(words: impl IntoIterator<Item = &'a str>) -> Slot {
+    Slot::Words(words.into_iter().map(str::to_string).collect())
+}
+
+/// The shapes of the common expressions, built from the parser's word lists in the order they
+/// are ranked
+fn templates(parser: &FlexiParser) -> Vec<Vec<Slot>> {
+    let locale = &parser.locale;
+    let vocabulary = &parser.vocabulary;
+
+    let days = || {
+        words(
+            [locale.today, locale.tomorrow, locale.yesterday]
+                .into_iter()
+                .flatten()
+                .copied()
+                .chain(vocabulary.dates.iter().map(|(name, _)| name.as_str())),
+        )
+    };
+    let weekdays = || words(locale.weekdays.into_iter().flatten().copied());
+    let months = || words(locale.months.into_iter().flatten().copied());
+    let units = || {
+        let mut units = locale
+            .units
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect::<Vec<_>>();
+        for (name, _) in &vocabulary.units {
+            units.extend([format!("{name}s"), name.clone()]);
+        }
+        Slot::Words(units)
+    };
+    let time = || {
+        Slot::Time(
+            vocabulary
+                .times
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect(),
+        )
+    };
+    let next = || words(locale.this_before.iter().chain(locale.next_before).copied());
+    let word = |word: &str| words([word]);
+
+    let mut templates = vec![
+        vec![days()],
+        vec![days(), time()],
+        vec![days(), words(locale.time_before.iter().copied()), time()],
+        vec![weekdays()],
+        vec![next(), weekdays()],
+        vec![weekdays(), words(locale.next_after.iter().copied())],
+        vec![weekdays(), time()],
+        vec![
+            weekdays(),
+            words(locale.time_before.iter().copied()),
+            time(),
+        ],
+        vec![months()],
+        vec![months(), Slot::Number],
+        vec![next(), months()],
+        vec![time()],
+        vec![words(locale.time_before.iter().copied()), time()],
+        vec![time(), days()],
+        vec![Slot::Number, units()],
+        vec![
+            words(locale.future_before.iter().copied()),
+            Slot::Number,
+            units(),
+        ],
+        vec![
+            words(locale.past_before.iter().copied()),
+            Slot::Number,
+            units(),
+        ],
+        vec![
+            Slot::Number,
+            units(),
+            words(locale.past_after.iter().copied()),
+        ],
+        vec![
+            Slot::Number,
+            units(),
+            words(locale.future_after.iter().copied()),
+        ],
+    ];
+
+    if *locale == Locale::ENGLISH {
+        let offsets = || words(["this", "next", "last", "previous"]);
+        let periods = || words(["day", "week", "month", "quarter", "year"]);
+        let boundaries = || words(["start", "beginning", "middle", "end"]);
+        let nth = || words(["first", "second", "third", "fourth", "fifth", "last"]);
+
+        templates.extend([
+            vec![word("now")],
+            vec![words(["last", "previous"]), weekdays()],
+            vec![offsets(), words(["week", "month", "quarter"])],
+            vec![weekdays(), offsets(), word("week")],
+            vec![word("week"), Slot::Number],
+            vec![
+                words(["next", "last", "previous"]),
+                words(["business day", "working day"]),
+            ],
+            vec![boundaries(), word("of"), offsets(), periods()],
+            vec![boundaries(), word("of"), word("the"), periods()],
+            vec![nth(), weekdays(), word("of"), months()],
+            vec![nth(), weekdays(), word("of"), word("the"), word("month")],
+        ]);
+        templates.extend(
+            parser
+                .holidays
+                .holidays()
+                .iter()
+                .flat_map(|holiday| holiday.names())
+                .map(|name| vec![word(name)]),
+        );
+    }
+
+    // Drop the shapes using words the locale does not have
+    templates.retain(|template| {
+        template
+            .iter()
+            .all(|slot| !matches!(slot, Slot::Words(words) if words.is_empty()))
+    });
+    templates
+}
+
+fn is_number(token: &str) -> bool {
+    !token.is_empty() && token.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_time(token: &str, names: &[String]) -> bool {
+    names.iter().any(|name| name.eq_ignore_ascii_case(token))
+        || matches!(parse_wall_clock_time(token), Ok(("", _)))
+}
+
+/// Finds each position in `template` reached by typing `tokens`, starting at `slot`
+fn advance<'t>(template: &'t [Slot], slot: usize, tokens: &[&str]) -> Vec<Position<'t>> {
+    if tokens.is_empty() {
+        return vec![Position { slot, within: None }];
+    }
+    let Some(current) = template.get(slot) else {
+        return Vec::new();
+    };
+
+    match current {
+        Slot::Number if is_number(tokens[0]) => advance(template, slot + 1, &tokens[1..]),
+        Slot::Time(names) if is_time(tokens[0], names) => advance(template, slot + 1, &tokens[1..]),
+        Slot::Words(phrases) => phrases
+            .iter()
+            .flat_map(|phrase| {
+                let words = phrase.split_whitespace().collect::<Vec<_>>();
+                let typed = words.len().min(tokens.len());
+                let matches = words
+                    .iter()
+                    .zip(tokens)
+                    .all(|(word, token)| word.eq_ignore_ascii_case(token));
+
+                match (matches, typed < words.len()) {
+                    (false, _) => Vec::new(),
+                    (true, true) => vec![Position {
+                        slot,
+                        within: Some((phrase.as_str(), typed)),
+                    }],
+                    (true, false) => advance(template, slot + 1, &tokens[typed..]),
+                }
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Whether `word` starts with `partial` but is longer than it, ignoring case
+fn continues(word: &str, partial: &str) -> bool {
+    word.len() > partial.len()
+        && word
+            .get(..partial.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(partial))
+}
+
+/// The words which may be typed at `slot` to continue the text, each with whether it completes
+/// `partial` rather than following it
+fn proposals(template: &[Slot], position: Position, partial: &str) -> Vec<(String, bool)> {
+    if let Some((phrase, typed)) = position.within {
+        let rest = phrase.split_whitespace().skip(typed).collect::<Vec<_>>();
+        return match partial {
+            "" => vec![(rest.join(" "), false)],
+            partial if continues(rest[0], partial) || rest[0].eq_ignore_ascii_case(partial) => {
+                vec![(rest.join(" "), true)]
+            }
+            _ => Vec::new(),
+        };
+    }
+
+    let Some(slot) = template.get(position.slot) else {
+        return Vec::new();
+    };
+    let (phrases, matched) = match slot {
+        Slot::Words(phrases) => (
+            phrases.as_slice(),
+            phrases
+                .iter()
+                .any(|phrase| phrase.eq_ignore_ascii_case(partial)),
+        ),
+        Slot::Number => (&[][..], is_number(partial)),
+        Slot::Time(names) => (names.as_slice(), is_time(partial, names)),
+    };
+
+    let mut found = phrases
+        .iter()
+        // Single letters, such as `s` for seconds, are accepted but not worth offering
+        .filter(|phrase| phrase.chars().nth(1).is_some())
+        .filter(|phrase| partial.is_empty() || continues(phrase, partial))
+        .map(|phrase| (phrase.clone(), !partial.is_empty()))
+        .collect::<Vec<_>>();
+
+    // A word typed in full is followed by the words of the next slot, as in `next` to
+    // `next monday`, unless it could still become a longer word
+    if matched && !partial.is_empty() && found.is_empty() {
+        let next = Position {
+            slot: position.slot + 1,
+            within: None,
+        };
+        found.extend(proposals(template, next, ""));
+    }
+    found
+}
+
+/// Whether `partial` could be the start of a word typed at `position`
+fn is_started(template: &[Slot], position: Position, partial: &str) -> bool {
+    if partial.is_empty() {
+        return true;
+    }
+    if let Some((phrase, typed)) = position.within {
+        return phrase
+            .split_whitespace()
+            .nth(typed)
+            .is_some_and(|word| continues(word, partial) || word.eq_ignore_ascii_case(partial));
+    }
+
+    match template.get(position.slot) {
+        Some(Slot::Words(phrases)) => phrases
+            .iter()
+            .any(|phrase| continues(phrase, partial) || phrase.eq_ignore_ascii_case(partial)),
+        Some(Slot::Number) => is_number(partial),
+        // Times are only checked loosely, as `5` may become `5pm`
+        Some(Slot::Time(names)) => {
+            partial.starts_with(|c: char| c.is_ascii_digit())
+                || names.iter().any(|name| continues(name, partial))
+        }
+        None => false,
+    }
+}
+
+impl FlexiParser {
+    /// Parses the whole of `text`, if it is a whole expression
+    fn parse_whole(&self, text: &str) -> Option<ParsedTime> {
+        match self.parse_time(text) {
+            Ok((rest, time)) if rest.trim().is_empty() => Some(time),
+            _ => None,
+        }
+    }
+
+    /// Finds the ways of continuing `prefix`, by completing the word being typed or by adding
+    /// the next word. Completions follow the shapes of the common expressions, such as
+    /// `next <weekday>` or `in <number> <unit>`.
+    pub fn complete(&self, prefix: &str) -> Completions {
+        let mut tokens = prefix.split_whitespace().collect::<Vec<_>>();
+        let partial = match prefix.ends_with(char::is_whitespace) {
+            true => "",
+            false => tokens.pop().unwrap_or_default(),
+        };
+        let typed = &prefix[..prefix.len() - partial.len()];
+
+        let mut valid_prefix = prefix.trim().is_empty();
+        let mut seen = HashSet::new();
+        let mut candidates = Vec::new();
+
+        for template in &templates(self) {
+            for position in advance(template, 0, &tokens) {
+                valid_prefix |= is_started(template, position, partial);
+
+                for (words, completes) in proposals(template, position, partial) {
+                    let text = if completes || partial.is_empty() {
+                        format!("{typed}{words}")
+                    } else {
+                        format!("{prefix} {words}")
+                    };
+
+                    if seen.insert(text.to_lowercase()) {
+                        candidates.push(Completion {
+                            time: self.parse_whole(&text),
+                            text,
+                        });
+                    }
+                }
+            }
+        }
+
+        // Stable, so each group keeps the order of the templates
+        candidates.sort_by_key(|candidate| candidate.time.is_none());
+
+        Completions {
+            valid_prefix: valid_prefix || self.parse_whole(prefix).is_some(),
+            candidates,
+        }
+    }
+}
+
+/// Finds the ways of continuing `prefix` with the default configuration
+pub fn complete(prefix: &str) -> Completions {
+    FlexiParser::shared().complete(prefix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{Vocabulary, relative::units::RelativeUnit};
+
+    fn texts(prefix: &str) -> Vec<String> {
+        complete(prefix)
+            .candidates
+            .into_iter()
+            .map(|candidate| candidate.text)
+            .collect()
+    }
+
+    #[test]
+    fn test_complete_word() {
+        let completions = complete("next we");
+        assert!(completions.valid_prefix);
+        assert_eq!(
+            completions
+                .candidates
+                .iter()
+                .map(|candidate| candidate.text.as_str())
+                .collect::<Vec<_>>(),
+            ["next wednesday", "next wed", "next week"]
+        );
+        assert!(completions.candidates.iter().all(|c| c.time.is_some()));
+
+        assert_eq!(texts("3 mo"), ["3 months", "3 month", "3 mos"]);
+        assert_eq!(texts("tom"), ["tomorrow"]);
+    }
+
+    #[test]
+    fn test_complete_next_word() {
+        let completions = texts("in 3");
+        assert!(completions.contains(&"in 3 days".to_string()));
+        assert!(completions.contains(&"in 3 business days".to_string()));
+        assert!(!completions.contains(&"in 3 s".to_string()));
+        assert!(!completions.contains(&"in 3 monday".to_string()));
+
+        assert_eq!(
+            texts("in 3 business d"),
+            ["in 3 business days", "in 3 business day"]
+        );
+        assert!(texts("next ").contains(&"next friday".to_string()));
+        assert!(texts("friday next").contains(&"friday next week".to_string()));
+        assert!(complete("tomorrow at").valid_prefix);
+        assert!(complete("tomorrow at 5").valid_prefix);
+    }
+
+    #[test]
+    fn test_ranks_whole_expressions_first() {
+        let completions = complete("e");
+        assert!(completions.valid_prefix);
+        assert_eq!(completions.candidates[0].text, "easter monday");
+        assert!(completions.candidates[0].time.is_some());
+        assert_eq!(
+            completions.candidates.last(),
+            Some(&Completion {
+                text: "end".to_string(),
+                time: None
+            })
+        );
+
+        assert_eq!(texts("end"), ["end of"]);
+        assert_eq!(texts("fri"), ["friday"]);
+    }
+
+    #[test]
+    fn test_invalid_prefix() {
+        assert!(!complete("next wx").valid_prefix);
+        assert!(!complete("whenever").valid_prefix);
+        assert!(complete("").valid_prefix);
+        assert!(complete("tomorrow").valid_prefix);
+        assert!(texts("whenever").is_empty());
+    }
+
+    #[test]
+    fn test_vocabulary_and_holidays() {
+        let parser = FlexiParser::new().vocabulary(Vocabulary::new().duration(
+            "sprint",
+            RelativeUnit::Weeks,
+            2,
+        ));
+        let completions = parser.complete("in 2 spr");
+        assert_eq!(completions.candidates[0].text, "in 2 sprints");
+
+        assert_eq!(texts("christ")[0], "christmas");
+    }
+
+    #[test]
+    fn test_locale() {
+        let parser = FlexiParser::new().locale(Locale::FRENCH);
+        let completions = parser.complete("dem");
+        assert_eq!(completions.candidates[0].text, "demain");
+        assert!(completions.candidates[0].time.is_some());
+    }
+}
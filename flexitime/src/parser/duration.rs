@@ -0,0 +1,222 @@
+use std::time::Duration;
+
+use chrono::TimeDelta;
+use nom::{
+    Parser,
+    branch::alt,
+    bytes::complete::tag_no_case,
+    character::complete::{char, space0, space1},
+    combinator::{opt, value},
+    multi::separated_list1,
+};
+
+use super::{
+    FlexiParser,
+    relative::units::{RelativeUnit, parse_unit_with},
+};
+use crate::error::FlexitimeResult;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+/// The average length of a year in the Gregorian calendar, 365.2425 days
+const SECONDS_PER_AVERAGE_YEAR: u64 = 31_556_952;
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum DurationError {
+    #[error("{0:?} do not have a fixed length")]
+    CalendarUnit(RelativeUnit),
+
+    #[error("A duration cannot be in the past or future, as with 'in' or 'ago'")]
+    Direction,
+
+    #[error("The duration is too long to be represented")]
+    Overflow,
+}
+
+/// How months, quarters and years are treated when parsing a duration, as their length
+/// depends on when they are counted from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CalendarUnits {
+    /// Reject calendar units
+    #[default]
+    Reject,
+    /// Use the average length of each unit in the Gregorian calendar, so a year is 365.2425
+    /// days and a month is a twelfth of that
+    Average,
+}
+
+/// A fixed length of time, such as `1h 30m`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ParsedDuration {
+    duration: Duration,
+}
+
+impl ParsedDuration {
+    pub fn to_std(&self) -> Duration {
+        self.duration
+    }
+
+    pub fn to_time_delta(&self) -> TimeDelta {
+        // Parsing rejects anything longer than TimeDelta::MAX
+        TimeDelta::from_std(self.duration).unwrap()
+    }
+}
+
+impl From<ParsedDuration> for Duration {
+    fn from(duration: ParsedDuration) -> Self {
+        duration.to_std()
+    }
+}
+
+impl From<ParsedDuration> for TimeDelta {
+    fn from(duration: ParsedDuration) -> Self {
+        duration.to_time_delta()
+    }
+}
+
+/// The length of a unit in seconds
+fn unit_seconds(unit: &RelativeUnit, policy: CalendarUnits) -> Result<u64, DurationError> {
+    Ok(match (unit, policy) {
+        (RelativeUnit::Seconds, _) => 1,
+        (RelativeUnit::Minutes, _) => 60,
+        (RelativeUnit::Hours, _) => 60 * 60,
+        (RelativeUnit::Days, _) => SECONDS_PER_DAY,
+        (RelativeUnit::Weeks, _) => 7 * SECONDS_PER_DAY,
+        (RelativeUnit::Months, CalendarUnits::Average) => SECONDS_PER_AVERAGE_YEAR / 12,
+        (RelativeUnit::Quarters, CalendarUnits::Average) => SECONDS_PER_AVERAGE_YEAR / 4,
+        (RelativeUnit::Years, CalendarUnits::Average) => SECONDS_PER_AVERAGE_YEAR,
+        (unit, _) => return Err(DurationError::CalendarUnit(unit.clone())),
+    })
+}
+
+fn unit_separator(input: &str) -> FlexitimeResult<&str, ()> {
+    alt((
+        value(
+            (),
+            (space0, char(','), space0, opt((tag_no_case("and"), space1))),
+        ),
+        value((), (space1, tag_no_case("and"), space1)),
+        value((), space0),
+    ))
+    .parse(input)
+}
+
+fn direction_error<T>() -> FlexitimeResult<&'static str, T> {
+    Err(nom::Err::Error(DurationError::Direction.into()))
+}
+
+pub fn parse_duration(input: &str) -> FlexitimeResult<&str, ParsedDuration> {
+    parse_duration_with(input, FlexiParser::shared())
+}
+
+pub fn parse_duration_with<'a>(
+    input: &'a str,
+    parser: &FlexiParser,
+) -> FlexitimeResult<&'a str, ParsedDuration> {
+    if opt((tag_no_case("in"), space1)).parse(input)?.1.is_some() {
+        return direction_error();
+    }
+
+    let (rest, units) =
+        separated_list1(unit_separator, |i| parse_unit_with(i, parser)).parse(input)?;
+
+    let suffix: FlexitimeResult<&str, _> =
+        (space1, alt((tag_no_case("ago"), tag_no_case("hence")))).parse(rest);
+    if suffix.is_ok() {
+        return direction_error();
+    }
+
+    let seconds = units.iter().try_fold(0u64, |total, unit| {
+        let seconds = unit_seconds(&unit.unit, parser.calendar_units)?;
+        u64::from(unit.amount)
+            .checked_mul(seconds)
+            .and_then(|seconds| total.checked_add(seconds))
+            .filter(|total| {
+                i64::try_from(*total)
+                    .ok()
+                    .and_then(TimeDelta::try_seconds)
+                    .is_some()
+            })
+            .ok_or(DurationError::Overflow)
+    });
+
+    match seconds {
+        Ok(seconds) => Ok((
+            rest,
+            ParsedDuration {
+                duration: Duration::from_secs(seconds),
+            },
+        )),
+        Err(err) => Err(nom::Err::Error(err.into())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::FlexitimeError;
+
+    use super::*;
+
+    fn seconds(input: &str) -> u64 {
+        let (rest, duration) = parse_duration(input).unwrap();
+        assert_eq!(rest, "");
+        duration.to_std().as_secs()
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(seconds("30s"), 30);
+        assert_eq!(seconds("1h 30m"), 5400);
+        assert_eq!(seconds("1h30m"), 5400);
+        assert_eq!(seconds("2 days"), 2 * SECONDS_PER_DAY);
+        assert_eq!(seconds("1 hour, 15 minutes and 10 seconds"), 4510);
+
+        let (_, duration) = parse_duration("90 minutes").unwrap();
+        assert_eq!(duration.to_time_delta(), TimeDelta::minutes(90));
+        assert_eq!(Duration::from(duration), Duration::from_secs(5400));
+    }
+
+    #[test]
+    fn test_calendar_units() {
+        assert_eq!(
+            parse_duration("2 months"),
+            Err(nom::Err::Error(FlexitimeError::Duration(
+                DurationError::CalendarUnit(RelativeUnit::Months)
+            )))
+        );
+        assert_eq!(
+            parse_duration("3 business days"),
+            Err(nom::Err::Error(FlexitimeError::Duration(
+                DurationError::CalendarUnit(RelativeUnit::BusinessDays)
+            )))
+        );
+
+        let parser = FlexiParser::new().calendar_units(CalendarUnits::Average);
+        let (_, duration) = parser.parse_duration("1 year").unwrap();
+        assert_eq!(duration.to_std().as_secs(), SECONDS_PER_AVERAGE_YEAR);
+        assert!(parser.parse_duration("1 business day").is_err());
+    }
+
+    #[test]
+    fn test_rejects_direction() {
+        for input in ["in 5 minutes", "5 minutes ago"] {
+            assert_eq!(
+                parse_duration(input),
+                Err(nom::Err::Error(FlexitimeError::Duration(
+                    DurationError::Direction
+                ))),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_overflow() {
+        let parser = FlexiParser::new().calendar_units(CalendarUnits::Average);
+        assert_eq!(
+            parser.parse_duration("4000000000 years"),
+            Err(nom::Err::Error(FlexitimeError::Duration(
+                DurationError::Overflow
+            )))
+        );
+    }
+}
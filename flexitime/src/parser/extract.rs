@@ -0,0 +1,128 @@
+use std::ops::Range;
+
+use super::{FlexiParser, ParsedTime};
+
+/// Words which are usually not meant as times when found on their own in free text
+pub(crate) const AMBIGUOUS_WORDS: [&str; 5] = ["may", "mar", "sat", "sun", "wed"];
+
+/// A time expression found in a piece of text
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeMatch<'a> {
+    /// The byte range of the expression within the text
+    pub span: Range<usize>,
+    pub text: &'a str,
+    pub time: ParsedTime,
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric()
+}
+
+/// The byte offsets at which a word starts
+fn word_starts(text: &str) -> impl Iterator<Item = usize> + '_ {
+    text.char_indices()
+        .scan(None, |previous: &mut Option<char>, (index, c)| {
+            let starts_word = is_word_char(c) && !previous.is_some_and(is_word_char);
+            *previous = Some(c);
+            Some((index, starts_word))
+        })
+        .filter_map(|(index, starts_word)| starts_word.then_some(index))
+}
+
+/// Whether a match is too likely to be an ordinary word or number to be reported
+fn is_noise(text: &str) -> bool {
+    text.chars().all(|c| c.is_ascii_digit())
+        || AMBIGUOUS_WORDS
+            .iter()
+            .any(|word| word.eq_ignore_ascii_case(text))
+}
+
+/// Finds every time expression in `text`, such as a chat message, preferring the longest
+/// expressions where they overlap
+pub fn find_all(text: &str) -> Vec<TimeMatch<'_>> {
+    find_all_with(text, FlexiParser::shared())
+}
+
+pub fn find_all_with<'a>(text: &'a str, parser: &FlexiParser) -> Vec<TimeMatch<'a>> {
+    let mut candidates = word_starts(text)
+        .filter_map(|start| {
+            let (rest, time) = parser.parse_time(&text[start..]).ok()?;
+            let matched = text[start..text.len() - rest.len()].trim_end();
+            let end = start + matched.len();
+
+            let ends_word = !text[end..].starts_with(is_word_char);
+            (ends_word && !matched.is_empty() && !is_noise(matched)).then_some(TimeMatch {
+                span: start..end,
+                text: matched,
+                time,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // Prefer the longest matches, then the earliest
+    candidates.sort_by_key(|candidate| {
+        (
+            std::cmp::Reverse(candidate.span.len()),
+            candidate.span.start,
+        )
+    });
+
+    let mut found = Vec::<TimeMatch>::new();
+    for candidate in candidates {
+        let overlaps = found.iter().any(|existing| {
+            candidate.span.start < existing.span.end && existing.span.start < candidate.span.end
+        });
+        if !overlaps {
+            found.push(candidate);
+        }
+    }
+
+    found.sort_by_key(|found| found.span.start);
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_timestring;
+
+    fn texts(text: &str) -> Vec<&str> {
+        find_all(text).into_iter().map(|found| found.text).collect()
+    }
+
+    #[test]
+    fn test_find_all() {
+        let text = "let's meet next tuesday at 3pm, or friday morning";
+        let found = find_all(text);
+
+        assert_eq!(
+            found.iter().map(|found| found.text).collect::<Vec<_>>(),
+            ["next tuesday at 3pm", "friday"]
+        );
+        assert_eq!(found[0].span, 11..30);
+        assert_eq!(&text[found[1].span.clone()], "friday");
+        assert_eq!(
+            found[0].time,
+            parse_timestring("next tuesday at 3pm").unwrap().1
+        );
+    }
+
+    #[test]
+    fn test_prefers_longest_match() {
+        assert_eq!(
+            texts("The report is due in 3 business days, not 2 days ago."),
+            ["in 3 business days", "2 days ago"]
+        );
+        assert_eq!(
+            texts("Deploy at end of next week or christmas eve 6pm"),
+            ["end of next week", "christmas eve 6pm"]
+        );
+    }
+
+    #[test]
+    fn test_ignores_noise() {
+        assert!(find_all("We may need 3 people, who sat quietly").is_empty());
+        assert!(find_all("Mondays are a montage of monsters").is_empty());
+        assert_eq!(texts("Is 2025-10-25 free?"), ["2025-10-25"]);
+    }
+}
@@ -0,0 +1,303 @@
+use chrono::{Datelike, Month, NaiveDate, NaiveDateTime};
+
+use super::{
+    FlexiParser, ParsedTime, absolute, absolute::FlexiDate, extract::AMBIGUOUS_WORDS, relative,
+};
+use crate::resolve::{ResolveError, ResolveOptions};
+
+/// The weight of the usual reading of an input, and of readings which are as likely
+const USUAL: f64 = 1.0;
+/// The weight of a reading which is less likely than the usual one, but plausible
+const ALTERNATIVE: f64 = 0.5;
+/// The weight of a reading which only some conventions use
+const UNLIKELY: f64 = 0.25;
+
+/// One way of reading an ambiguous input
+#[derive(Debug, Clone, PartialEq)]
+pub struct Interpretation {
+    pub time: ParsedTime,
+    /// The time resolved against the anchor given to [`parse_all`]
+    pub resolved: Result<NaiveDateTime, ResolveError>,
+    /// How the input was read, e.g. `in 5 months, reading m as months`
+    pub explanation: String,
+    /// How likely this is to be what was meant, between 0 and 1. The confidences of the
+    /// interpretations of an input add up to 1, unless it may not be a time at all.
+    pub confidence: f64,
+}
+
+/// A reading of the input, before it is resolved and weighed against the others
+struct Reading {
+    time: ParsedTime,
+    note: Option<&'static str>,
+    weight: f64,
+}
+
+/// Parses the whole of `input`, if it is a whole expression
+fn parse_whole(
+    input: &str,
+    parse: impl Fn(&str) -> crate::error::FlexitimeResult<&str, ParsedTime>,
+) -> Option<ParsedTime> {
+    match parse(input) {
+        Ok((rest, time)) if rest.trim().is_empty() => Some(time),
+        _ => None,
+    }
+}
+
+/// Swaps the day and month of numeric dates such as `01/02/2025`, where either could be the
+/// month
+fn month_first(input: &str) -> Option<String> {
+    let mut swapped = false;
+    let words = input
+        .split_whitespace()
+        .map(|word| {
+            let Some(delimiter) = word.chars().find(|c| *c == '/' || *c == '-') else {
+                return word.to_string();
+            };
+            let parts = word.split(delimiter).collect::<Vec<_>>();
+
+            match parts[..] {
+                [day, month, year]
+                    if year.len() == 4
+                        && [day, month].iter().all(|part| {
+                            part.parse::<u8>()
+                                .is_ok_and(|value| (1..=12).contains(&value))
+                        })
+                        && day.parse::<u8>() != month.parse::<u8>() =>
+                {
+                    swapped = true;
+                    [month, day, year].join(&delimiter.to_string())
+                }
+                _ => word.to_string(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    swapped.then(|| words.join(" "))
+}
+
+/// Reads the `m` unit as months rather than minutes, as in `5m`
+fn m_as_months(input: &str) -> Option<String> {
+    let mut rewritten = false;
+    let mut previous_is_number = false;
+    let words = input
+        .split_whitespace()
+        .map(|word| {
+            let number = word.trim_end_matches(['m', 'M']);
+            let is_m = word.len() == number.len() + 1
+                && (previous_is_number && number.is_empty()
+                    || !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()));
+            previous_is_number = word.chars().all(|c| c.is_ascii_digit());
+
+            if is_m {
+                rewritten = true;
+                format!("{number}mo")
+            } else {
+                word.to_string()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rewritten.then(|| words.join(" "))
+}
+
+/// Reads a number beside a month as a day of that month, as in `12 mar` or `march 12th`, taking
+/// the next such date
+fn day_of_month(input: &str, parser: &FlexiParser, anchor: NaiveDateTime) -> Option<ParsedTime> {
+    let parse_day = |word: &str| {
+        ["st", "nd", "rd", "th"]
+            .iter()
+            .find_map(|suffix| word.strip_suffix(suffix))
+            .unwrap_or(word)
+            .parse::<u32>()
+            .ok()
+    };
+    let parse_month = |word: &str| match parser.locale.parse_month(word) {
+        Ok(("", month)) => Some(month),
+        _ => None,
+    };
+
+    let (day, month) = match input.split_whitespace().collect::<Vec<_>>()[..] {
+        [first, second] => parse_day(first)
+            .zip(parse_month(second))
+            .or_else(|| parse_day(second).zip(parse_month(first)))?,
+        _ => return None,
+    };
+
+    let in_year = |year| NaiveDate::from_ymd_opt(year, Month::number_from_month(&month), day);
+    let today = anchor.date();
+    let date = in_year(today.year())
+        .filter(|date| *date >= today)
+        .or_else(|| in_year(today.year() + 1))?;
+
+    Some(ParsedTime::Absolute(
+        absolute::AbsoluteTimeBuilder::new()
+            .date(FlexiDate::Date(date))
+            .build()
+            .ok()?,
+    ))
+}
+
+impl FlexiParser {
+    /// Every plausible reading of `input` which uses the whole of it, such as `5m` meaning
+    /// either 5 minutes or 5 months. Readings are ordered from the most to the least likely.
+    pub fn parse_all(
+        &self,
+        input: &str,
+        anchor: NaiveDateTime,
+        options: &ResolveOptions,
+    ) -> Vec<Interpretation> {
+        let mut readings = Vec::new();
+        let mut add = |time: Option<ParsedTime>, note, weight| {
+            if let Some(time) = time
+                && !readings
+                    .iter()
+                    .any(|reading: &Reading| reading.time == time)
+            {
+                readings.push(Reading { time, note, weight });
+            }
+        };
+
+        add(parse_whole(input, |i| self.parse_time(i)), None, USUAL);
+        add(
+            day_of_month(input, self, anchor),
+            Some("reading the number as the day of the month"),
+            USUAL,
+        );
+        if self.locale == super::Locale::ENGLISH {
+            add(
+                parse_whole(input, |i| {
+                    relative::parse_relative_time_with(i, self)
+                        .map(|(rest, time)| (rest, ParsedTime::Relative(time)))
+                }),
+                Some("reading it as relative to now"),
+                ALTERNATIVE,
+            );
+            add(
+                parse_whole(input, |i| {
+                    absolute::parse_absolute_time_with(i, self)
+                        .map(|(rest, time)| (rest, ParsedTime::Absolute(time)))
+                }),
+                Some("reading it as a date or time of day"),
+                ALTERNATIVE,
+            );
+        }
+        add(
+            month_first(input).and_then(|input| parse_whole(&input, |i| self.parse_time(i))),
+            Some("reading the date as month first"),
+            ALTERNATIVE,
+        );
+        add(
+            m_as_months(input).and_then(|input| parse_whole(&input, |i| self.parse_time(i))),
+            Some("reading m as months"),
+            UNLIKELY,
+        );
+
+        // Words such as `may` are as likely to be meant as an ordinary word
+        let is_word = AMBIGUOUS_WORDS
+            .iter()
+            .any(|word| word.eq_ignore_ascii_case(input.trim()));
+        let not_a_time = if is_word { USUAL } else { 0.0 };
+        let total = readings.iter().map(|reading| reading.weight).sum::<f64>() + not_a_time;
+
+        let mut interpretations = readings
+            .into_iter()
+            .map(|reading| Interpretation {
+                resolved: reading.time.resolve(anchor, options),
+                explanation: match reading.note {
+                    Some(note) => format!("{}, {note}", reading.time),
+                    None => reading.time.to_string(),
+                },
+                confidence: reading.weight / total,
+                time: reading.time,
+            })
+            .collect::<Vec<_>>();
+
+        // Stable, so equally likely readings keep their order
+        interpretations.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        interpretations
+    }
+}
+
+/// Every plausible reading of `input` with the default configuration
+pub fn parse_all(
+    input: &str,
+    anchor: NaiveDateTime,
+    options: &ResolveOptions,
+) -> Vec<Interpretation> {
+    FlexiParser::shared().parse_all(input, anchor, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{anchor, datetime};
+
+    fn interpret(input: &str) -> Vec<(NaiveDateTime, String, f64)> {
+        parse_all(input, anchor(), &ResolveOptions::default())
+            .into_iter()
+            .map(|interpretation| {
+                (
+                    interpretation.resolved.unwrap(),
+                    interpretation.explanation,
+                    interpretation.confidence,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_minutes_or_months() {
+        assert_eq!(
+            interpret("5m"),
+            [
+                (
+                    datetime(2025, 10, 22, 14, 35),
+                    "in 5 minutes".to_string(),
+                    0.8
+                ),
+                (
+                    datetime(2026, 3, 22, 14, 30),
+                    "in 5 months, reading m as months".to_string(),
+                    0.2
+                ),
+            ]
+        );
+        assert_eq!(interpret("2 hours 30 m ago").len(), 2);
+    }
+
+    #[test]
+    fn test_day_or_month_first() {
+        let interpretations = interpret("01/02/2025");
+        assert_eq!(interpretations.len(), 2);
+        assert_eq!(interpretations[0].1, "2025-02-01");
+        assert_eq!(
+            interpretations[1].1,
+            "2025-01-02, reading the date as month first"
+        );
+        assert!(interpretations[0].2 > interpretations[1].2);
+
+        assert_eq!(interpret("13/02/2025").len(), 1);
+        assert_eq!(interpret("02/02/2025").len(), 1);
+    }
+
+    #[test]
+    fn test_day_of_month() {
+        let interpretations = interpret("12 mar");
+        assert_eq!(interpretations.len(), 2);
+        assert_eq!(interpretations[0].0, datetime(2026, 3, 22, 12, 0));
+        assert_eq!(interpretations[1].0, datetime(2026, 3, 12, 0, 0));
+        assert_eq!(interpretations[0].2, 0.5);
+
+        assert_eq!(interpret("october 30th")[0].0, datetime(2025, 10, 30, 0, 0));
+    }
+
+    #[test]
+    fn test_ordinary_words() {
+        let interpretations = interpret("may");
+        assert_eq!(interpretations.len(), 1);
+        assert_eq!(interpretations[0].2, 0.5);
+
+        assert_eq!(interpret("tomorrow")[0].2, 1.0);
+        assert!(interpret("whenever").is_empty());
+    }
+}
@@ -0,0 +1,43 @@
+use super::Locale;
+use crate::parser::relative::units::RelativeUnit;
+
+impl Locale {
+    pub const ENGLISH: Locale = Locale {
+        today: &["today"],
+        tomorrow: &["tomorrow"],
+        yesterday: &["yesterday"],
+        weekdays: [
+            &["monday", "mon"],
+            &["tuesday", "tue"],
+            &["wednesday", "wed"],
+            &["thursday", "thu"],
+            &["friday", "fri"],
+            &["saturday", "sat"],
+            &["sunday", "sun"],
+        ],
+        months: [
+            &["january", "jan"],
+            &["february", "feb"],
+            &["march", "mar"],
+            &["april", "apr"],
+            &["may"],
+            &["june", "jun"],
+            &["july", "jul"],
+            &["august", "aug"],
+            &["september", "sep"],
+            &["october", "oct"],
+            &["november", "nov"],
+            &["december", "dec"],
+        ],
+        this_before: &["this"],
+        next_before: &["next"],
+        next_after: &[],
+        units: RelativeUnit::NAMES,
+        future_before: &["in"],
+        future_after: &["hence"],
+        past_before: &[],
+        past_after: &["ago"],
+        time_before: &["at"],
+        hour_markers: &[],
+    };
+}
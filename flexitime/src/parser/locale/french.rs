@@ -0,0 +1,72 @@
+use super::Locale;
+use crate::parser::relative::units::RelativeUnit;
+
+impl Locale {
+    pub const FRENCH: Locale = Locale {
+        today: &["aujourd'hui", "aujourd’hui"],
+        tomorrow: &["demain"],
+        yesterday: &["hier"],
+        weekdays: [
+            &["lundi", "lun"],
+            &["mardi", "mar"],
+            &["mercredi", "mer"],
+            &["jeudi", "jeu"],
+            &["vendredi", "ven"],
+            &["samedi", "sam"],
+            &["dimanche", "dim"],
+        ],
+        months: [
+            &["janvier", "janv"],
+            &["février", "fevrier", "févr", "fevr"],
+            &["mars"],
+            &["avril", "avr"],
+            &["mai"],
+            &["juin"],
+            &["juillet", "juil"],
+            &["août", "aout"],
+            &["septembre", "sept"],
+            &["octobre", "oct"],
+            &["novembre", "nov"],
+            &["décembre", "decembre", "déc", "dec"],
+        ],
+        this_before: &["ce", "cet"],
+        next_before: &[],
+        next_after: &["prochain", "prochaine"],
+        // `h` is left out, as it marks the hour in times such as `15h30`
+        units: &[
+            ("secondes", RelativeUnit::Seconds),
+            ("seconde", RelativeUnit::Seconds),
+            ("sec", RelativeUnit::Seconds),
+            ("s", RelativeUnit::Seconds),
+            ("minutes", RelativeUnit::Minutes),
+            ("minute", RelativeUnit::Minutes),
+            ("min", RelativeUnit::Minutes),
+            ("heures", RelativeUnit::Hours),
+            ("heure", RelativeUnit::Hours),
+            ("jours", RelativeUnit::Days),
+            ("jour", RelativeUnit::Days),
+            ("j", RelativeUnit::Days),
+            ("jours ouvrés", RelativeUnit::BusinessDays),
+            ("jour ouvré", RelativeUnit::BusinessDays),
+            ("jours ouvrables", RelativeUnit::BusinessDays),
+            ("jour ouvrable", RelativeUnit::BusinessDays),
+            ("semaines", RelativeUnit::Weeks),
+            ("semaine", RelativeUnit::Weeks),
+            ("mois", RelativeUnit::Months),
+            ("trimestres", RelativeUnit::Quarters),
+            ("trimestre", RelativeUnit::Quarters),
+            ("années", RelativeUnit::Years),
+            ("année", RelativeUnit::Years),
+            ("annees", RelativeUnit::Years),
+            ("annee", RelativeUnit::Years),
+            ("ans", RelativeUnit::Years),
+            ("an", RelativeUnit::Years),
+        ],
+        future_before: &["dans"],
+        future_after: &[],
+        past_before: &["il y a"],
+        past_after: &[],
+        time_before: &["à", "a"],
+        hour_markers: &["h"],
+    };
+}
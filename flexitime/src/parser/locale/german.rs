@@ -0,0 +1,82 @@
+use super::Locale;
+use crate::parser::relative::units::RelativeUnit;
+
+impl Locale {
+    pub const GERMAN: Locale = Locale {
+        today: &["heute"],
+        tomorrow: &["morgen"],
+        yesterday: &["gestern"],
+        weekdays: [
+            &["montag"],
+            &["dienstag"],
+            &["mittwoch"],
+            &["donnerstag"],
+            &["freitag"],
+            &["samstag", "sonnabend"],
+            &["sonntag"],
+        ],
+        months: [
+            &["januar", "jänner", "jan"],
+            &["februar", "feb"],
+            &["märz", "maerz", "mär"],
+            &["april", "apr"],
+            &["mai"],
+            &["juni", "jun"],
+            &["juli", "jul"],
+            &["august", "aug"],
+            &["september", "sept", "sep"],
+            &["oktober", "okt"],
+            &["november", "nov"],
+            &["dezember", "dez"],
+        ],
+        this_before: &["diesen"],
+        next_before: &[
+            "nächsten",
+            "nächster",
+            "nächste",
+            "naechsten",
+            "kommenden",
+            "kommender",
+            "am",
+        ],
+        next_after: &[],
+        units: &[
+            ("sekunden", RelativeUnit::Seconds),
+            ("sekunde", RelativeUnit::Seconds),
+            ("sek", RelativeUnit::Seconds),
+            ("s", RelativeUnit::Seconds),
+            ("minuten", RelativeUnit::Minutes),
+            ("minute", RelativeUnit::Minutes),
+            ("min", RelativeUnit::Minutes),
+            ("stunden", RelativeUnit::Hours),
+            ("stunde", RelativeUnit::Hours),
+            ("std", RelativeUnit::Hours),
+            ("tagen", RelativeUnit::Days),
+            ("tage", RelativeUnit::Days),
+            ("tag", RelativeUnit::Days),
+            ("arbeitstagen", RelativeUnit::BusinessDays),
+            ("arbeitstage", RelativeUnit::BusinessDays),
+            ("arbeitstag", RelativeUnit::BusinessDays),
+            ("werktagen", RelativeUnit::BusinessDays),
+            ("werktage", RelativeUnit::BusinessDays),
+            ("werktag", RelativeUnit::BusinessDays),
+            ("wochen", RelativeUnit::Weeks),
+            ("woche", RelativeUnit::Weeks),
+            ("monaten", RelativeUnit::Months),
+            ("monate", RelativeUnit::Months),
+            ("monat", RelativeUnit::Months),
+            ("quartalen", RelativeUnit::Quarters),
+            ("quartale", RelativeUnit::Quarters),
+            ("quartal", RelativeUnit::Quarters),
+            ("jahren", RelativeUnit::Years),
+            ("jahre", RelativeUnit::Years),
+            ("jahr", RelativeUnit::Years),
+        ],
+        future_before: &["in"],
+        future_after: &[],
+        past_before: &["vor"],
+        past_after: &[],
+        time_before: &["um"],
+        hour_markers: &["uhr"],
+    };
+}
@@ -0,0 +1,544 @@
+use chrono::{Month, Weekday};
+use nom::{
+    Parser,
+    branch::alt,
+    bytes::complete::take_while_m_n,
+    character::complete::{digit1, space0, space1},
+    combinator::{map, map_res, opt, value},
+    error::{ErrorKind, ParseError},
+    multi::{fold_many1, separated_list1},
+    sequence::{delimited, preceded, terminated},
+};
+
+use super::{
+    FlexiParser, ParsedTime,
+    absolute::{
+        AbsoluteTime, AbsoluteTimeBuilder, AbsoluteTimePart, DayOffset, FlexiDate, MonthOffset,
+        WallClockTime, date, date::parse_year, holiday, wallclock_time,
+    },
+    relative::{
+        time::RelativeTime,
+        units::{ParsedUnit, RelativeUnit},
+    },
+};
+use crate::error::{FlexitimeError, FlexitimeResult};
+
+mod english;
+mod french;
+mod german;
+mod spanish;
+
+/// The words of a language, used to parse time strings written in it.
+///
+/// Words are matched ignoring case and must be followed by a word boundary. Where a language
+/// allows several spellings, such as with and without accents, each is listed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Locale {
+    pub today: &'static [&'static str],
+    pub tomorrow: &'static [&'static str],
+    pub yesterday: &'static [&'static str],
+    /// The names of each weekday, starting with Monday
+    pub weekdays: [&'static [&'static str]; 7],
+    /// The names of each month, starting with January
+    pub months: [&'static [&'static str]; 12],
+    /// Words placed before a weekday to mean that day of the current week, e.g. `this`. Before
+    /// a month they mean its next occurrence.
+    pub this_before: &'static [&'static str],
+    /// Words placed before a weekday or month to mean its next occurrence, e.g. `next`
+    pub next_before: &'static [&'static str],
+    /// Words placed after a weekday or month to mean its next occurrence, e.g. `prochain`
+    pub next_after: &'static [&'static str],
+    pub units: &'static [(&'static str, RelativeUnit)],
+    /// Words placed before a relative time in the future, e.g. `in`
+    pub future_before: &'static [&'static str],
+    /// Words placed after a relative time in the future, e.g. `hence`
+    pub future_after: &'static [&'static str],
+    /// Words placed before a relative time in the past, e.g. `vor`
+    pub past_before: &'static [&'static str],
+    /// Words placed after a relative time in the past, e.g. `ago`
+    pub past_after: &'static [&'static str],
+    /// Words introducing a time of day, e.g. `at`
+    pub time_before: &'static [&'static str],
+    /// Words marking the hour of a time of day, e.g. `h` in `15h30` or `uhr` in `15 uhr`
+    pub hour_markers: &'static [&'static str],
+}
+
+/// Strips `word` from the start of `input`, ignoring case
+pub(crate) fn strip_word<'a>(input: &'a str, word: &str) -> Option<&'a str> {
+    let mut chars = input.chars();
+    for expected in word.chars() {
+        if !chars.next()?.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+    }
+
+    let rest = chars.as_str();
+    (!rest.starts_with(char::is_alphabetic)).then_some(rest)
+}
+
+/// Parses the longest of `words` found at the start of `input`
+pub(crate) fn parse_word<T>(
+    input: &str,
+    words: impl IntoIterator<Item = (&'static str, T)>,
+) -> FlexitimeResult<&str, T> {
+    words
+        .into_iter()
+        .filter_map(|(word, value)| strip_word(input, word).map(|rest| (rest, value)))
+        .min_by_key(|(rest, _)| rest.len())
+        .ok_or_else(|| nom::Err::Error(FlexitimeError::from_error_kind(input, ErrorKind::Tag)))
+}
+
+/// Pairs each word in a list with the same value
+fn words<T: Clone>(
+    words: &'static [&'static str],
+    value: T,
+) -> impl Iterator<Item = (&'static str, T)> {
+    words.iter().map(move |word| (*word, value.clone()))
+}
+
+/// Parses a word which must be followed by a space, such as `next` in `next monday`
+fn parse_before<'a>(
+    input: &'a str,
+    before: &'static [&'static str],
+) -> FlexitimeResult<&'a str, ()> {
+    terminated(|i| parse_word(i, words(before, ())), space1).parse(input)
+}
+
+/// Which occurrence of a weekday or month is meant by the words around it
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Occurrence {
+    This,
+    Next,
+}
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+const MONTHS: [Month; 12] = [
+    Month::January,
+    Month::February,
+    Month::March,
+    Month::April,
+    Month::May,
+    Month::June,
+    Month::July,
+    Month::August,
+    Month::September,
+    Month::October,
+    Month::November,
+    Month::December,
+];
+
+impl Locale {
+    pub(crate) fn parse_weekday<'a>(&self, input: &'a str) -> FlexitimeResult<&'a str, Weekday> {
+        parse_word(
+            input,
+            WEEKDAYS
+                .into_iter()
+                .zip(self.weekdays)
+                .flat_map(|(weekday, names)| words(names, weekday)),
+        )
+    }
+
+    pub(crate) fn parse_month<'a>(&self, input: &'a str) -> FlexitimeResult<&'a str, Month> {
+        parse_word(
+            input,
+            MONTHS
+                .into_iter()
+                .zip(self.months)
+                .flat_map(|(month, names)| words(names, month)),
+        )
+    }
+
+    /// Parses a name, allowing for words marking its occurrence in the current week or its next
+    /// occurrence. Also returns which occurrence was asked for, if any.
+    fn parse_occurrence<'a, T>(
+        &self,
+        input: &'a str,
+        name: impl Parser<&'a str, Output = T, Error = FlexitimeError<&'a str>>,
+    ) -> FlexitimeResult<&'a str, (Option<Occurrence>, T)> {
+        map(
+            (
+                opt(alt((
+                    value(Occurrence::This, |i| parse_before(i, self.this_before)),
+                    value(Occurrence::Next, |i| parse_before(i, self.next_before)),
+                ))),
+                name,
+                opt(preceded(space1, |i| {
+                    parse_word(i, words(self.next_after, Occurrence::Next))
+                })),
+            ),
+            |(before, name, after)| (before.or(after), name),
+        )
+        .parse(input)
+    }
+
+    fn parse_day_offset<'a>(&self, input: &'a str) -> FlexitimeResult<&'a str, DayOffset> {
+        alt((
+            |i| {
+                parse_word(
+                    i,
+                    words(self.today, DayOffset::Fixed(0))
+                        .chain(words(self.tomorrow, DayOffset::Fixed(1)))
+                        .chain(words(self.yesterday, DayOffset::Fixed(-1))),
+                )
+            },
+            map(
+                |i| self.parse_occurrence(i, |i| self.parse_weekday(i)),
+                |(occurrence, weekday)| match occurrence {
+                    Some(Occurrence::This) => DayOffset::ThisDayOccurrence(weekday),
+                    Some(Occurrence::Next) => DayOffset::NextDayOccurrence(weekday),
+                    None => DayOffset::Named(weekday),
+                },
+            ),
+        ))
+        .parse(input)
+    }
+
+    fn parse_month_offset<'a>(&self, input: &'a str) -> FlexitimeResult<&'a str, MonthOffset> {
+        alt((
+            map(
+                (|i| self.parse_month(i), space1, parse_year),
+                |(month, _, year)| MonthOffset::InYear(month, year.into()),
+            ),
+            map(
+                |i| self.parse_occurrence(i, |i| self.parse_month(i)),
+                |(occurrence, month)| match occurrence {
+                    Some(_) => MonthOffset::NextMonthOccurrence(month),
+                    None => MonthOffset::Named(month),
+                },
+            ),
+        ))
+        .parse(input)
+    }
+
+    /// Parses a time of day, such as `15:30`, `15h30` or `15 uhr`
+    fn parse_wall_clock_time<'a>(&self, input: &'a str) -> FlexitimeResult<&'a str, WallClockTime> {
+        let hour_marker = |i| parse_word(i, words(self.hour_markers, ()));
+        let two_digits = || {
+            map_res(
+                take_while_m_n(2, 2, |c: char| c.is_ascii_digit()),
+                |s: &str| s.parse::<u8>(),
+            )
+        };
+
+        preceded(
+            opt(|i| parse_before(i, self.time_before)),
+            alt((
+                map_res(
+                    (
+                        map_res(
+                            take_while_m_n(1, 2, |c: char| c.is_ascii_digit()),
+                            |s: &str| s.parse::<u8>(),
+                        ),
+                        space0,
+                        hour_marker,
+                        opt(two_digits()),
+                    ),
+                    |(hour, _, _, minute)| WallClockTime::new(hour, minute.unwrap_or(0), 0, None),
+                ),
+                terminated(
+                    wallclock_time::parse_wall_clock_time,
+                    opt(preceded(space0, hour_marker)),
+                ),
+            )),
+        )
+        .parse(input)
+    }
+
+    fn parse_absolute_time<'a>(
+        &self,
+        input: &'a str,
+        parser: &FlexiParser,
+    ) -> FlexitimeResult<&'a str, AbsoluteTime> {
+        fold_many1(
+            delimited(
+                space0,
+                alt((
+                    map(
+                        |i| parser.vocabulary.parse_date(i),
+                        AbsoluteTimePart::NamedDate,
+                    ),
+                    map(
+                        preceded(opt(|i| parse_before(i, self.time_before)), |i| {
+                            parser.vocabulary.parse_time(i)
+                        }),
+                        AbsoluteTimePart::WallClockTime,
+                    ),
+                    map(
+                        |i| holiday::parse_holiday(i, parser.holiday_calendar()),
+                        AbsoluteTimePart::Holiday,
+                    ),
+                    map(date::parse_date, AbsoluteTimePart::Date),
+                    map(|i| self.parse_day_offset(i), AbsoluteTimePart::DayOffset),
+                    map(
+                        |i| self.parse_month_offset(i),
+                        AbsoluteTimePart::MonthOffset,
+                    ),
+                    map(
+                        |i| self.parse_wall_clock_time(i),
+                        AbsoluteTimePart::WallClockTime,
+                    ),
+                )),
+                space0,
+            ),
+            AbsoluteTimeBuilder::new,
+            |acc, part| match part {
+                AbsoluteTimePart::NamedDate(date) => acc.date(date),
+                AbsoluteTimePart::Holiday(holiday) => acc.date(FlexiDate::Holiday(holiday)),
+                AbsoluteTimePart::Date(date) => acc.date(FlexiDate::Date(date)),
+                AbsoluteTimePart::DayOffset(offset) => acc.date(FlexiDate::DayOffset(offset)),
+                AbsoluteTimePart::MonthOffset(offset) => acc.date(FlexiDate::MonthOffset(offset)),
+                AbsoluteTimePart::WallClockTime(time) => acc.time(time),
+                _ => acc,
+            },
+        )
+        .map(|builder| builder.build().unwrap())
+        .parse(input)
+    }
+
+    fn parse_relative_time<'a>(
+        &self,
+        input: &'a str,
+        parser: &FlexiParser,
+    ) -> FlexitimeResult<&'a str, RelativeTime> {
+        let (input, before) = opt(terminated(
+            |i| {
+                parse_word(
+                    i,
+                    words(self.past_before, true).chain(words(self.future_before, false)),
+                )
+            },
+            space1,
+        ))
+        .parse(input)?;
+
+        let (input, units) = separated_list1(
+            space1,
+            alt((
+                |i| parser.vocabulary.parse_unit(i),
+                map(
+                    (map_res(digit1, |s: &str| s.parse::<u32>()), space0, |i| {
+                        parse_word(i, self.units.iter().cloned())
+                    }),
+                    |(amount, _, unit)| ParsedUnit { unit, amount },
+                ),
+            )),
+        )
+        .parse(input)?;
+
+        let (input, after) = opt(preceded(space1, |i| {
+            parse_word(
+                i,
+                words(self.past_after, true).chain(words(self.future_after, false)),
+            )
+        }))
+        .parse(input)?;
+
+        let mut time = units
+            .into_iter()
+            .fold(RelativeTime::default(), |time, unit| {
+                time.unit(unit.unit, unit.amount)
+            });
+        time.negative = before.or(after).unwrap_or(false);
+
+        Ok((input, time))
+    }
+
+    /// Parses a time string written in this language. Only day names, weekdays, months, times of
+    /// day, numeric dates and relative times are supported, along with holidays and the words of
+    /// a parser's [`Vocabulary`](super::Vocabulary).
+    pub fn parse<'a>(&self, input: &'a str) -> FlexitimeResult<&'a str, ParsedTime> {
+        self.parse_with(input, FlexiParser::shared())
+    }
+
+    /// Parses a time string written in this language, consulting the vocabulary and holidays of
+    /// `parser` before the built-in words
+    pub(crate) fn parse_with<'a>(
+        &self,
+        input: &'a str,
+        parser: &FlexiParser,
+    ) -> FlexitimeResult<&'a str, ParsedTime> {
+        alt((
+            map(
+                |i| self.parse_relative_time(i, parser),
+                ParsedTime::Relative,
+            ),
+            map(
+                |i| self.parse_absolute_time(i, parser),
+                ParsedTime::Absolute,
+            ),
+        ))
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    use super::*;
+    use crate::{
+        fixtures::{anchor, datetime},
+        holiday::{Holiday, HolidayRule, HolidaySet},
+        parser::Vocabulary,
+        resolve::ResolveOptions,
+    };
+
+    fn resolve(locale: Locale, input: &str) -> NaiveDateTime {
+        let (rest, parsed) = locale.parse(input).unwrap();
+        assert_eq!(rest, "", "{input}");
+        parsed
+            .resolve(anchor(), &ResolveOptions::default())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_french() {
+        let locale = Locale::FRENCH;
+
+        assert_eq!(resolve(locale, "demain 15h"), datetime(2025, 10, 23, 15, 0));
+        assert_eq!(
+            resolve(locale, "lundi prochain à 9h30"),
+            datetime(2025, 10, 27, 9, 30)
+        );
+        assert_eq!(
+            resolve(locale, "il y a 2 jours"),
+            datetime(2025, 10, 20, 14, 30)
+        );
+        assert_eq!(
+            resolve(locale, "dans 3 heures"),
+            datetime(2025, 10, 22, 17, 30)
+        );
+        assert_eq!(
+            resolve(locale, "Aujourd'hui 18h"),
+            datetime(2025, 10, 22, 18, 0)
+        );
+        assert_eq!(resolve(locale, "décembre"), datetime(2025, 12, 22, 0, 0));
+    }
+
+    #[test]
+    fn test_german() {
+        let locale = Locale::GERMAN;
+
+        assert_eq!(
+            resolve(locale, "vor 2 Tagen"),
+            datetime(2025, 10, 20, 14, 30)
+        );
+        assert_eq!(
+            resolve(locale, "morgen um 15 Uhr"),
+            datetime(2025, 10, 23, 15, 0)
+        );
+        assert_eq!(
+            resolve(locale, "nächsten Montag 9:30 Uhr"),
+            datetime(2025, 10, 27, 9, 30)
+        );
+        assert_eq!(
+            resolve(locale, "in 1 Woche"),
+            datetime(2025, 10, 29, 14, 30)
+        );
+        assert_eq!(
+            resolve(locale, "diesen Montag"),
+            datetime(2025, 10, 20, 0, 0)
+        );
+        assert_eq!(
+            resolve(locale, "März 2026"),
+            NaiveDate::from_ymd_opt(2026, 3, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_locale_with_vocabulary() {
+        let parser = FlexiParser::new()
+            .locale(Locale::FRENCH)
+            .holidays(HolidaySet::new().holiday(Holiday::new(
+                "noël",
+                HolidayRule::Fixed {
+                    month: Month::December,
+                    day: 25,
+                },
+            )))
+            .vocabulary(
+                Vocabulary::new()
+                    .time("standup", WallClockTime::new(9, 30, 0, None).unwrap())
+                    .duration("sprint", RelativeUnit::Weeks, 2)
+                    .date(
+                        "paie",
+                        FlexiDate::Date(NaiveDate::from_ymd_opt(2025, 10, 31).unwrap()),
+                    ),
+            );
+        let resolve = |input| {
+            let (rest, parsed) = parser.parse(input).unwrap();
+            assert_eq!(rest, "", "{input}");
+            parsed
+                .resolve(anchor(), &ResolveOptions::default())
+                .unwrap()
+        };
+
+        assert_eq!(resolve("demain standup"), datetime(2025, 10, 23, 9, 30));
+        assert_eq!(resolve("demain à standup"), datetime(2025, 10, 23, 9, 30));
+        assert_eq!(resolve("dans 2 sprints"), datetime(2025, 11, 19, 14, 30));
+        assert_eq!(resolve("paie 15h"), datetime(2025, 10, 31, 15, 0));
+        assert_eq!(resolve("noël"), datetime(2025, 12, 25, 0, 0));
+    }
+
+    #[test]
+    fn test_spanish() {
+        let locale = Locale::SPANISH;
+
+        assert_eq!(
+            resolve(locale, "próximo lunes"),
+            datetime(2025, 10, 27, 0, 0)
+        );
+        assert_eq!(
+            resolve(locale, "el lunes que viene"),
+            datetime(2025, 10, 27, 0, 0)
+        );
+        assert_eq!(
+            resolve(locale, "hace 2 días"),
+            datetime(2025, 10, 20, 14, 30)
+        );
+        assert_eq!(
+            resolve(locale, "mañana a las 15:00"),
+            datetime(2025, 10, 23, 15, 0)
+        );
+        assert_eq!(
+            resolve(locale, "dentro de 3 días hábiles"),
+            datetime(2025, 10, 27, 14, 30)
+        );
+    }
+
+    #[test]
+    fn test_english() {
+        let locale = Locale::ENGLISH;
+
+        assert_eq!(
+            resolve(locale, "next friday at 9pm"),
+            datetime(2025, 10, 24, 21, 0)
+        );
+        assert_eq!(
+            resolve(locale, "2 days ago"),
+            datetime(2025, 10, 20, 14, 30)
+        );
+    }
+
+    #[test]
+    fn test_word_boundaries() {
+        assert!(Locale::GERMAN.parse("morgens").is_err());
+        assert!(Locale::FRENCH.parse("demain").is_ok());
+        assert_eq!(
+            Locale::SPANISH.parse_weekday("miercoles"),
+            Ok(("", Weekday::Wed))
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use super::Locale;
+use crate::parser::relative::units::RelativeUnit;
+
+impl Locale {
+    pub const SPANISH: Locale = Locale {
+        today: &["hoy"],
+        tomorrow: &["mañana", "manana"],
+        yesterday: &["ayer"],
+        weekdays: [
+            &["lunes"],
+            &["martes"],
+            &["miércoles", "miercoles"],
+            &["jueves"],
+            &["viernes"],
+            &["sábado", "sabado"],
+            &["domingo"],
+        ],
+        months: [
+            &["enero", "ene"],
+            &["febrero", "feb"],
+            &["marzo", "mar"],
+            &["abril", "abr"],
+            &["mayo"],
+            &["junio", "jun"],
+            &["julio", "jul"],
+            &["agosto", "ago"],
+            &["septiembre", "setiembre", "sept"],
+            &["octubre", "oct"],
+            &["noviembre", "nov"],
+            &["diciembre", "dic"],
+        ],
+        this_before: &["este"],
+        next_before: &[
+            "el próximo",
+            "el proximo",
+            "la próxima",
+            "la proxima",
+            "próximo",
+            "proximo",
+            "el",
+        ],
+        next_after: &["próximo", "proximo", "que viene"],
+        units: &[
+            ("segundos", RelativeUnit::Seconds),
+            ("segundo", RelativeUnit::Seconds),
+            ("seg", RelativeUnit::Seconds),
+            ("s", RelativeUnit::Seconds),
+            ("minutos", RelativeUnit::Minutes),
+            ("minuto", RelativeUnit::Minutes),
+            ("min", RelativeUnit::Minutes),
+            ("horas", RelativeUnit::Hours),
+            ("hora", RelativeUnit::Hours),
+            ("días", RelativeUnit::Days),
+            ("dias", RelativeUnit::Days),
+            ("día", RelativeUnit::Days),
+            ("dia", RelativeUnit::Days),
+            ("días hábiles", RelativeUnit::BusinessDays),
+            ("dias habiles", RelativeUnit::BusinessDays),
+            ("día hábil", RelativeUnit::BusinessDays),
+            ("dia habil", RelativeUnit::BusinessDays),
+            ("días laborables", RelativeUnit::BusinessDays),
+            ("dias laborables", RelativeUnit::BusinessDays),
+            ("día laborable", RelativeUnit::BusinessDays),
+            ("dia laborable", RelativeUnit::BusinessDays),
+            ("semanas", RelativeUnit::Weeks),
+            ("semana", RelativeUnit::Weeks),
+            ("meses", RelativeUnit::Months),
+            ("mes", RelativeUnit::Months),
+            ("trimestres", RelativeUnit::Quarters),
+            ("trimestre", RelativeUnit::Quarters),
+            ("años", RelativeUnit::Years),
+            ("año", RelativeUnit::Years),
+        ],
+        future_before: &["dentro de", "en"],
+        future_after: &[],
+        past_before: &["hace"],
+        past_after: &[],
+        time_before: &["a las", "a la"],
+        hour_markers: &["h"],
+    };
+}
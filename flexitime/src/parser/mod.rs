@@ -1,26 +1,160 @@
+use std::sync::{Arc, LazyLock};
+
 use nom::{Parser, branch::alt, combinator::map};
-use time::ParsedTime;
 
-use crate::error::FlexitimeResult;
+use crate::{
+    error::FlexitimeResult,
+    holiday::{HolidayCalendar, HolidaySet},
+    recurrence::Recurrence,
+};
 
 pub mod absolute;
+pub mod complete;
+pub mod duration;
+pub mod extract;
+pub mod interpret;
+pub mod locale;
+pub mod range;
+pub mod recurrence;
 pub mod relative;
 mod time;
+pub mod typos;
+pub mod vocabulary;
+pub use complete::{Completion, Completions, complete};
+pub use duration::{CalendarUnits, ParsedDuration, parse_duration};
+pub use extract::{TimeMatch, find_all};
+pub use interpret::{Interpretation, parse_all};
+pub use locale::Locale;
+pub use range::{RangeBound, TimeRange};
+pub use time::ParsedTime;
+pub use typos::{Suggestion, Typos};
+pub use vocabulary::Vocabulary;
+
+static SHARED_PARSER: LazyLock<FlexiParser> = LazyLock::new(FlexiParser::default);
+
+/// A time string parser with its own configuration. [`parse_timestring`] uses a parser with the
+/// default configuration.
+#[derive(Debug, Clone)]
+pub struct FlexiParser {
+    holidays: Arc<dyn HolidayCalendar>,
+    calendar_units: CalendarUnits,
+    locale: Locale,
+    vocabulary: Vocabulary,
+    typos: Typos,
+}
+
+impl Default for FlexiParser {
+    fn default() -> Self {
+        Self {
+            holidays: Arc::new(HolidaySet::builtin()),
+            calendar_units: CalendarUnits::default(),
+            locale: Locale::ENGLISH,
+            vocabulary: Vocabulary::default(),
+            typos: Typos::default(),
+        }
+    }
+}
+
+impl FlexiParser {
+    pub fn new() -> Self {
+        FlexiParser::default()
+    }
+
+    /// The parser with the default configuration
+    pub(crate) fn shared() -> &'static FlexiParser {
+        &SHARED_PARSER
+    }
+
+    /// Sets the calendar consulted when parsing holiday names
+    pub fn holidays(mut self, calendar: impl HolidayCalendar + 'static) -> Self {
+        self.holidays = Arc::new(calendar);
+        self
+    }
+
+    /// Sets how months, quarters and years are treated when parsing a duration
+    pub fn calendar_units(mut self, policy: CalendarUnits) -> Self {
+        self.calendar_units = policy;
+        self
+    }
+
+    /// Sets the language in which time strings are written
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets the words, such as company jargon, consulted before the built-in ones
+    pub fn vocabulary(mut self, vocabulary: Vocabulary) -> Self {
+        self.vocabulary = vocabulary;
+        self
+    }
+
+    /// Sets how words which are a small typo away from a known word are treated
+    pub fn typos(mut self, policy: Typos) -> Self {
+        self.typos = policy;
+        self
+    }
+
+    pub fn holiday_calendar(&self) -> &dyn HolidayCalendar {
+        self.holidays.as_ref()
+    }
+
+    pub fn parse<'a>(&self, input: &'a str) -> FlexitimeResult<&'a str, ParsedTime> {
+        self.parse_with_typos(input, |i| self.parse_time(i))
+    }
+
+    /// Parses a point in time without looking for typos, for use within other parsers
+    pub(crate) fn parse_time<'a>(&self, input: &'a str) -> FlexitimeResult<&'a str, ParsedTime> {
+        if self.locale != Locale::ENGLISH {
+            return self.locale.parse_with(input, self);
+        }
+
+        alt((
+            map(
+                |i: &'a str| relative::parse_relative_time_with(i, self),
+                ParsedTime::Relative,
+            ),
+            map(
+                |i: &'a str| absolute::parse_absolute_time_with(i, self),
+                ParsedTime::Absolute,
+            ),
+        ))
+        .parse(input)
+    }
+
+    /// Finds every time expression in a piece of free text
+    pub fn find_all<'a>(&self, text: &'a str) -> Vec<TimeMatch<'a>> {
+        extract::find_all_with(text, self)
+    }
+
+    /// Parses a fixed length of time, such as `1h 30m`
+    pub fn parse_duration<'a>(&self, input: &'a str) -> FlexitimeResult<&'a str, ParsedDuration> {
+        self.parse_with_typos(input, |i| duration::parse_duration_with(i, self))
+    }
+
+    /// Parses a span of time, such as `from 9am to 5pm tomorrow`
+    pub fn parse_range<'a>(&self, input: &'a str) -> FlexitimeResult<&'a str, TimeRange> {
+        self.parse_with_typos(input, |i| range::parse_range_with(i, self))
+    }
+
+    /// Parses a repeating schedule, such as `every monday at 9am`
+    pub fn parse_recurrence<'a>(&self, input: &'a str) -> FlexitimeResult<&'a str, Recurrence> {
+        self.parse_with_typos(input, |i| recurrence::parse_recurrence_with(i, self))
+    }
+}
 
 pub fn parse_timestring(input: &str) -> FlexitimeResult<&str, ParsedTime> {
-    alt((
-        map(relative::parse_relative_time, |t| ParsedTime::Relative(t)),
-        map(absolute::parse_absolute_time, |t| ParsedTime::Absolute(t)),
-    ))
-    .parse(input)
+    FlexiParser::shared().parse(input)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::parser::absolute::{
-        AbsoluteTimeBuilder, DayOffset, FlexiDate, TimePeriod, WallClockTime,
+        AbsoluteTimeBuilder, DayOffset, FlexiDate, Quarter, TimePeriod, WallClockTime,
     };
 
+    use crate::holiday::{Holiday, HolidayRule};
+
     use super::*;
 
     #[test]
@@ -39,4 +173,148 @@ mod tests {
             )
         )
     }
+
+    #[test]
+    fn test_parse_quarter() {
+        assert_eq!(
+            parse_timestring("Q3 2025").unwrap(),
+            (
+                "",
+                ParsedTime::Absolute(
+                    AbsoluteTimeBuilder::new()
+                        .date(FlexiDate::Quarter(Quarter::Numbered {
+                            quarter: 3,
+                            year: Some(2025)
+                        }))
+                        .build()
+                        .unwrap()
+                )
+            )
+        )
+    }
+
+    #[test]
+    fn test_fiscal_numbers_end_at_digit_boundary() {
+        for input in [
+            "FY260",
+            "q12",
+            "next quarters",
+            "next fiscal years",
+            "next fyx",
+            "last quarterly",
+        ] {
+            assert!(!matches!(parse_timestring(input), Ok(("", _))), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_parse_holiday_with_time() {
+        let (rest, parsed) = parse_timestring("christmas eve 6pm").unwrap();
+        assert_eq!(rest, "");
+
+        let ParsedTime::Absolute(time) = parsed else {
+            panic!("Expected an absolute time");
+        };
+        assert!(matches!(time.date(), Some(FlexiDate::Holiday(_))));
+        assert_eq!(
+            time.time(),
+            Some(&WallClockTime::new(6, 0, 0, Some(TimePeriod::Pm)).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let inputs = [
+            "3pm tomorrow",
+            "today",
+            "now",
+            "Q3 2025",
+            "q2",
+            "fy26 q1",
+            "last friday",
+            "next quarter",
+            "FY 2027",
+            "last fiscal year",
+            "christmas eve 6pm",
+            "boxing day 2026",
+            "tuesday 9:00pm",
+            "this wednesday",
+            "next business day",
+            "previous working day",
+            "25/10/2025 11:25am",
+            "12:05:30 am",
+            "8:05:30 pm",
+            "december",
+            "next december",
+            "next friday",
+            "last month",
+            "march 2026",
+            "first Monday of November",
+            "the second tuesday of next month",
+            "last friday of the month",
+            "second-to-last fri in march 2026",
+            "last business Friday in March 2024",
+            "start of next week 9am",
+            "beginning of Q3",
+            "end of FY25",
+            "middle of the day",
+            "end of tomorrow",
+            "middle of the month in 2 months",
+            "2d 1h ago",
+            "3y 2mo 2d 1h 5m",
+            "in 1 quarter",
+            "2 working days ago",
+        ];
+
+        for input in inputs {
+            let (_, parsed) = parse_timestring(input).unwrap();
+            let canonical = parsed.to_string();
+
+            assert_eq!(
+                parse_timestring(&canonical),
+                Ok(("", parsed)),
+                "{input} was written as {canonical}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let display = |input| parse_timestring(input).unwrap().1.to_string();
+
+        assert_eq!(display("next friday 9pm"), "next friday at 21:00");
+        assert_eq!(display("friday 9pm"), "friday at 21:00");
+        assert_eq!(display("next december"), "next december");
+        assert_eq!(display("5m 3d 2mo"), "in 2 months 3 days 5 minutes");
+        assert_eq!(
+            display("beginning of the year 8:30:15"),
+            "start of this year at 08:30:15"
+        );
+        assert_eq!(
+            display("second-to-last fri in march 2026"),
+            "second to last friday of march 2026"
+        );
+    }
+
+    #[test]
+    fn test_parse_with_locale() {
+        let parser = FlexiParser::new().locale(Locale::FRENCH);
+
+        assert_eq!(parser.parse("demain 15h"), parse_timestring("tomorrow 3pm"));
+        assert!(parse_timestring("demain 15h").is_err());
+    }
+
+    #[test]
+    fn test_parse_with_custom_holidays() {
+        let parser = FlexiParser::new().holidays(HolidaySet::builtin().holiday(Holiday::new(
+            "founders day",
+            HolidayRule::Fixed {
+                month: chrono::Month::June,
+                day: 12,
+            },
+        )));
+
+        assert!(parser.parse("founders day").is_ok());
+        assert!(parse_timestring("founders day").is_err());
+    }
 }
@@ -0,0 +1,579 @@
+use chrono::{NaiveDateTime, NaiveTime, TimeDelta};
+use nom::{
+    Parser,
+    branch::alt,
+    bytes::complete::tag_no_case,
+    character::complete::{alpha1, digit1, space0, space1},
+    combinator::{map, map_opt, map_res, not, opt, value},
+    multi::separated_list1,
+    sequence::{preceded, terminated},
+};
+
+use super::{
+    FlexiParser, ParsedTime,
+    absolute::{
+        AbsoluteTime, AbsoluteTimeBuilder, Boundary, FlexiDate, Period, PeriodBoundary,
+        PeriodTarget, period_boundary::parse_period,
+    },
+    relative::{
+        time::RelativeTime,
+        units::{RelativeUnit, parse_unit_name_with, parse_unit_with},
+    },
+};
+use crate::{
+    error::FlexitimeResult,
+    resolve::{Preference, ResolveError, ResolveOptions},
+};
+
+/// One end of a [`TimeRange`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum RangeBound {
+    /// The anchor the range is resolved against, as in `since monday`
+    Now,
+    Time(ParsedTime),
+}
+
+impl RangeBound {
+    pub fn resolve(
+        &self,
+        anchor: NaiveDateTime,
+        options: &ResolveOptions,
+    ) -> Result<NaiveDateTime, ResolveError> {
+        match self {
+            RangeBound::Now => Ok(anchor),
+            RangeBound::Time(time) => time.resolve(anchor, options),
+        }
+    }
+
+    fn is_time_only(&self) -> bool {
+        matches!(self, RangeBound::Time(ParsedTime::Absolute(time)) if time.date().is_none())
+    }
+}
+
+/// A span of time, such as `from 9am to 5pm tomorrow` or `since monday`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeRange {
+    pub start: RangeBound,
+    pub end: RangeBound,
+    /// Whether the end covers the whole of the day or period it names, as in
+    /// `monday through friday`, rather than ending as it begins
+    pub inclusive: bool,
+}
+
+impl TimeRange {
+    pub fn new(start: RangeBound, end: RangeBound) -> Self {
+        Self {
+            start,
+            end,
+            inclusive: false,
+        }
+    }
+
+    pub fn inclusive(mut self) -> Self {
+        self.inclusive = true;
+        self
+    }
+
+    /// The window of the given length ending at the anchor, as in `last 7 days`
+    pub fn lookback(length: RelativeTime) -> Self {
+        TimeRange::new(
+            RangeBound::Time(ParsedTime::Relative(length.ago())),
+            RangeBound::Now,
+        )
+    }
+
+    /// The `count` whole periods before the current one, as in `last 3 complete days`
+    pub fn complete_periods(period: Period, count: u32) -> Self {
+        TimeRange::new(
+            period_start(period, -(count.min(i32::MAX as u32) as i32)),
+            period_start(period, 0),
+        )
+    }
+
+    /// The current period up to the anchor, as in `this week so far`
+    pub fn period_to_date(period: Period) -> Self {
+        TimeRange::new(period_start(period, 0), RangeBound::Now)
+    }
+
+    /// Resolves the range relative to `anchor`, giving its start and its exclusive end.
+    ///
+    /// A range ending now, as in `since monday`, starts at the most recent occurrence of its
+    /// start. An end which falls before the start relative to the anchor is looked for after the
+    /// start instead, so that `monday to friday` ends on the friday after that monday, while
+    /// `9am to 5pm tomorrow` keeps both ends tomorrow.
+    pub fn resolve(
+        &self,
+        anchor: NaiveDateTime,
+        options: &ResolveOptions,
+    ) -> Result<(NaiveDateTime, NaiveDateTime), ResolveError> {
+        let start = if self.end == RangeBound::Now {
+            self.start
+                .resolve(anchor, &options.clone().prefer(Preference::Past))?
+        } else {
+            self.start.resolve(anchor, options)?
+        };
+        let from_anchor = self.end.resolve(anchor, options)?;
+        let mut end = if from_anchor < start && self.end != RangeBound::Now {
+            self.end.resolve(start, options)?
+        } else {
+            from_anchor
+        };
+
+        if self.inclusive
+            && let RangeBound::Time(ParsedTime::Absolute(time)) = &self.end
+            && let (Some(date), None) = (time.date(), time.time())
+        {
+            let (_, period_end) = date.period().span(end.date(), options)?;
+            end = period_end.and_time(NaiveTime::MIN);
+        }
+
+        // A range between two times of day, such as `10pm to 2am`, runs past midnight
+        if end < start && self.start.is_time_only() && self.end.is_time_only() {
+            end = end
+                .checked_add_signed(TimeDelta::days(1))
+                .ok_or(ResolveError::OutOfRange)?;
+        }
+
+        if end < start {
+            return Err(ResolveError::InvertedRange {
+                start,
+                end: from_anchor,
+            });
+        }
+
+        Ok((start, end))
+    }
+}
+
+fn period_start(period: Period, offset: i32) -> RangeBound {
+    RangeBound::Time(ParsedTime::Absolute(
+        AbsoluteTimeBuilder::new()
+            .date(FlexiDate::PeriodBoundary(PeriodBoundary::new(
+                Boundary::Start,
+                PeriodTarget::Offset { period, offset },
+            )))
+            .build()
+            .unwrap(),
+    ))
+}
+
+/// Gives a time of day without a date the date of the other end of the range, so that
+/// `9am to 5pm tomorrow` starts tomorrow as well
+fn share_dates(mut range: TimeRange) -> TimeRange {
+    let (
+        RangeBound::Time(ParsedTime::Absolute(start)),
+        RangeBound::Time(ParsedTime::Absolute(end)),
+    ) = (&range.start, &range.end)
+    else {
+        return range;
+    };
+
+    let with_date = |time: &AbsoluteTime, date| {
+        let builder = AbsoluteTimeBuilder::new().date(date);
+        let builder = match time.time() {
+            Some(time) => builder.time(time.clone()),
+            None => builder,
+        };
+        builder
+            .build()
+            .map(|time| RangeBound::Time(ParsedTime::Absolute(time)))
+    };
+
+    match (start.date(), end.date()) {
+        (None, Some(date)) => {
+            if let Ok(start) = with_date(start, date.clone()) {
+                range.start = start;
+            }
+        }
+        (Some(date), None) => {
+            if let Ok(end) = with_date(end, date.clone()) {
+                range.end = end;
+            }
+        }
+        _ => {}
+    }
+
+    range
+}
+
+fn parse_bound<'a>(input: &'a str, parser: &FlexiParser) -> FlexitimeResult<&'a str, RangeBound> {
+    alt((
+        value(RangeBound::Now, terminated(tag_no_case("now"), not(alpha1))),
+        map(|i: &'a str| parser.parse_time(i), RangeBound::Time),
+    ))
+    .parse(input)
+}
+
+fn unit_period(unit: RelativeUnit) -> Option<Period> {
+    match unit {
+        RelativeUnit::Days => Some(Period::Day),
+        RelativeUnit::Weeks => Some(Period::Week),
+        RelativeUnit::Months => Some(Period::Month),
+        RelativeUnit::Quarters => Some(Period::Quarter),
+        RelativeUnit::Years => Some(Period::Year),
+        _ => None,
+    }
+}
+
+fn parse_u32(input: &str) -> FlexitimeResult<&str, u32> {
+    map_res(digit1, |s: &str| s.parse::<u32>()).parse(input)
+}
+
+/// Parses a window looking back from the anchor, such as `past 24 hours`, `last 3 complete
+/// days` or `this week so far`
+fn parse_lookback<'a>(input: &'a str, parser: &FlexiParser) -> FlexitimeResult<&'a str, TimeRange> {
+    let complete = map_opt(
+        (
+            opt(terminated(parse_u32, space1)),
+            terminated(alt((tag_no_case("complete"), tag_no_case("full"))), space1),
+            |i| parse_unit_name_with(i, parser),
+        ),
+        |(count, _, unit)| {
+            let period = unit_period(unit.unit)?;
+            Some(TimeRange::complete_periods(
+                period,
+                count.unwrap_or(1).checked_mul(unit.amount)?,
+            ))
+        },
+    );
+    let length = map(
+        alt((
+            map(
+                separated_list1(space1, |i| parse_unit_with(i, parser)),
+                |units| {
+                    units.into_iter().fold(RelativeTime::new(), |time, unit| {
+                        time.unit(unit.unit, unit.amount)
+                    })
+                },
+            ),
+            map(
+                |i| parse_unit_name_with(i, parser),
+                |unit| RelativeTime::new().unit(unit.unit, unit.amount),
+            ),
+        )),
+        TimeRange::lookback,
+    );
+
+    let so_far = (space1, tag_no_case("so"), space1, tag_no_case("far"));
+    let to_date = map(
+        alt((
+            terminated(
+                alt((
+                    value(Period::Day, tag_no_case("today")),
+                    preceded((tag_no_case("this"), space1), parse_period),
+                )),
+                so_far,
+            ),
+            terminated(
+                parse_period,
+                (space1, tag_no_case("to"), space1, tag_no_case("date")),
+            ),
+            terminated(
+                alt((
+                    value(Period::Week, tag_no_case("wtd")),
+                    value(Period::Month, tag_no_case("mtd")),
+                    value(Period::Quarter, tag_no_case("qtd")),
+                    value(Period::Year, tag_no_case("ytd")),
+                )),
+                not(alpha1),
+            ),
+        )),
+        TimeRange::period_to_date,
+    );
+
+    alt((
+        preceded(
+            (
+                alt((
+                    tag_no_case("last"),
+                    tag_no_case("past"),
+                    tag_no_case("previous"),
+                    tag_no_case("trailing"),
+                )),
+                space1,
+            ),
+            alt((complete, length)),
+        ),
+        to_date,
+    ))
+    .parse(input)
+}
+
+/// Parses the word joining the two ends of a range, returning whether the end is inclusive
+fn parse_connector(input: &str) -> FlexitimeResult<&str, bool> {
+    alt((
+        value(false, (space0, tag_no_case("-"), space0)),
+        value(
+            true,
+            (
+                space0,
+                alt((tag_no_case("through"), tag_no_case("thru"))),
+                space1,
+            ),
+        ),
+        value(
+            false,
+            (
+                space0,
+                alt((tag_no_case("until"), tag_no_case("till"), tag_no_case("to"))),
+                space1,
+            ),
+        ),
+    ))
+    .parse(input)
+}
+
+pub fn parse_range(input: &str) -> FlexitimeResult<&str, TimeRange> {
+    parse_range_with(input, FlexiParser::shared())
+}
+
+pub fn parse_range_with<'a>(
+    input: &'a str,
+    parser: &FlexiParser,
+) -> FlexitimeResult<&'a str, TimeRange> {
+    let bound = |i: &'a str| parse_bound(i, parser);
+
+    let between = map(
+        (
+            preceded((tag_no_case("between"), space1), bound),
+            preceded((space0, tag_no_case("and"), space1), bound),
+        ),
+        |(start, end)| TimeRange::new(start, end).inclusive(),
+    );
+    let since = map(preceded((tag_no_case("since"), space1), bound), |start| {
+        TimeRange::new(start, RangeBound::Now)
+    });
+    let until = map(
+        (
+            alt((
+                value(true, alt((tag_no_case("through"), tag_no_case("thru")))),
+                value(false, alt((tag_no_case("until"), tag_no_case("till")))),
+            )),
+            preceded(space1, bound),
+        ),
+        |(inclusive, end)| TimeRange {
+            start: RangeBound::Now,
+            end,
+            inclusive,
+        },
+    );
+    let from_to = map(
+        (
+            preceded((tag_no_case("from"), space1), bound),
+            parse_connector,
+            bound,
+        ),
+        |(start, inclusive, end)| TimeRange {
+            start,
+            end,
+            inclusive,
+        },
+    );
+    let bare = map(
+        (bound, parse_connector, bound),
+        |(start, inclusive, end)| TimeRange {
+            start,
+            end,
+            inclusive,
+        },
+    );
+
+    alt((
+        map(alt((between, since, until, from_to, bare)), share_dates),
+        |i: &'a str| parse_lookback(i, parser),
+    ))
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Weekday;
+
+    use crate::{
+        fixtures::{anchor, datetime},
+        parser::absolute::{DayOffset, FlexiDate, TimePeriod, WallClockTime},
+    };
+
+    use super::*;
+
+    fn resolve(input: &str) -> Result<(NaiveDateTime, NaiveDateTime), ResolveError> {
+        let (rest, range) = parse_range(input).unwrap();
+        assert_eq!(rest, "", "{input}");
+        range.resolve(anchor(), &ResolveOptions::default())
+    }
+
+    #[test]
+    fn test_parse_shares_dates() {
+        let tomorrow_at = |hour| {
+            RangeBound::Time(ParsedTime::Absolute(AbsoluteTime::new(
+                WallClockTime::new(hour, 0, 0, Some(TimePeriod::Am)).unwrap(),
+                FlexiDate::DayOffset(DayOffset::Fixed(1)),
+            )))
+        };
+
+        let (rest, range) = parse_range("from 9am to 5pm tomorrow").unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(range.start, tomorrow_at(9));
+        assert!(!range.inclusive);
+
+        assert_eq!(
+            resolve("from 9am to 5pm tomorrow"),
+            Ok((datetime(2025, 10, 23, 9, 0), datetime(2025, 10, 23, 17, 0)))
+        );
+        assert_eq!(
+            resolve("tomorrow 9am - 5pm"),
+            Ok((datetime(2025, 10, 23, 9, 0), datetime(2025, 10, 23, 17, 0)))
+        );
+    }
+
+    #[test]
+    fn test_between_is_inclusive() {
+        let (_, range) = parse_range("between Monday and Friday").unwrap();
+        assert!(range.inclusive);
+        assert_eq!(
+            range.start,
+            RangeBound::Time(ParsedTime::Absolute(
+                AbsoluteTimeBuilder::new()
+                    .date(FlexiDate::DayOffset(DayOffset::Named(Weekday::Mon)))
+                    .build()
+                    .unwrap()
+            ))
+        );
+
+        assert_eq!(
+            resolve("between friday and next monday"),
+            Ok((datetime(2025, 10, 24, 0, 0), datetime(2025, 10, 28, 0, 0)))
+        );
+        assert_eq!(
+            resolve("friday through sunday"),
+            Ok((datetime(2025, 10, 24, 0, 0), datetime(2025, 10, 27, 0, 0)))
+        );
+    }
+
+    #[test]
+    fn test_end_follows_start() {
+        for (input, end) in [
+            ("between Monday and Friday", datetime(2025, 11, 1, 0, 0)),
+            ("monday through friday", datetime(2025, 11, 1, 0, 0)),
+            ("from monday to friday", datetime(2025, 10, 31, 0, 0)),
+        ] {
+            assert_eq!(
+                resolve(input),
+                Ok((datetime(2025, 10, 27, 0, 0), end)),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_since_looks_back() {
+        assert_eq!(
+            resolve("since monday"),
+            Ok((datetime(2025, 10, 20, 0, 0), anchor()))
+        );
+        assert_eq!(
+            resolve("since friday"),
+            Ok((datetime(2025, 10, 17, 0, 0), anchor()))
+        );
+        assert_eq!(
+            resolve("since 5pm"),
+            Ok((datetime(2025, 10, 21, 17, 0), anchor()))
+        );
+    }
+
+    #[test]
+    fn test_open_ended_ranges() {
+        assert_eq!(
+            resolve("until 5pm"),
+            Ok((anchor(), datetime(2025, 10, 22, 17, 0)))
+        );
+        assert_eq!(
+            resolve("since 2 hours ago"),
+            Ok((
+                datetime(2025, 10, 22, 12, 0) + TimeDelta::minutes(30),
+                anchor()
+            ))
+        );
+        assert_eq!(
+            resolve("from 9am until now"),
+            Ok((datetime(2025, 10, 22, 9, 0), anchor()))
+        );
+    }
+
+    #[test]
+    fn test_overnight_and_inverted_ranges() {
+        assert_eq!(
+            resolve("10pm to 2am"),
+            Ok((datetime(2025, 10, 22, 22, 0), datetime(2025, 10, 23, 2, 0)))
+        );
+        assert_eq!(
+            resolve("tomorrow to yesterday"),
+            Err(ResolveError::InvertedRange {
+                start: datetime(2025, 10, 23, 0, 0),
+                end: datetime(2025, 10, 21, 0, 0)
+            })
+        );
+    }
+
+    #[test]
+    fn test_lookback_windows() {
+        let (_, range) = parse_range("last 7 days").unwrap();
+        assert_eq!(range, TimeRange::lookback(RelativeTime::new().days(7)));
+
+        assert_eq!(
+            resolve("past 24 hours"),
+            Ok((anchor() - TimeDelta::hours(24), anchor()))
+        );
+        assert_eq!(
+            resolve("last hour"),
+            Ok((anchor() - TimeDelta::hours(1), anchor()))
+        );
+        assert_eq!(
+            resolve("trailing 1h 30m"),
+            Ok((anchor() - TimeDelta::minutes(90), anchor()))
+        );
+    }
+
+    #[test]
+    fn test_complete_periods() {
+        assert_eq!(
+            resolve("last 3 complete days"),
+            Ok((datetime(2025, 10, 19, 0, 0), datetime(2025, 10, 22, 0, 0)))
+        );
+        assert_eq!(
+            resolve("last full week"),
+            Ok((datetime(2025, 10, 13, 0, 0), datetime(2025, 10, 20, 0, 0)))
+        );
+    }
+
+    #[test]
+    fn test_period_to_date() {
+        assert_eq!(
+            resolve("this week so far"),
+            Ok((datetime(2025, 10, 20, 0, 0), anchor()))
+        );
+        assert_eq!(
+            resolve("today so far"),
+            Ok((datetime(2025, 10, 22, 0, 0), anchor()))
+        );
+        assert_eq!(
+            resolve("month to date"),
+            Ok((datetime(2025, 10, 1, 0, 0), anchor()))
+        );
+        assert_eq!(resolve("YTD"), Ok((datetime(2025, 1, 1, 0, 0), anchor())));
+    }
+
+    #[test]
+    fn test_week_range() {
+        assert_eq!(
+            resolve("week 43 through week 44"),
+            Ok((datetime(2025, 10, 20, 0, 0), datetime(2025, 11, 3, 0, 0)))
+        );
+
+        let (_, range) = parse_range("from this week until next week").unwrap();
+        assert_eq!(
+            range.resolve(anchor(), &ResolveOptions::new().week_start(Weekday::Sun)),
+            Ok((datetime(2025, 10, 19, 0, 0), datetime(2025, 10, 26, 0, 0)))
+        );
+    }
+}
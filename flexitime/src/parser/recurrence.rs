@@ -0,0 +1,303 @@
+use chrono::Weekday;
+use nom::{
+    Parser,
+    branch::alt,
+    bytes::complete::tag_no_case,
+    character::complete::{char, digit1, space0, space1},
+    combinator::{map, map_opt, map_res, opt, value},
+    multi::{many0, separated_list1},
+    sequence::{preceded, terminated},
+};
+
+use super::{
+    FlexiParser, ParsedTime,
+    absolute::{WallClockTime, day_offset::parse_weekday, wallclock_time::parse_wall_clock_time},
+    relative::units::{RelativeUnit, parse_unit_name_with},
+};
+use crate::{error::FlexitimeResult, recurrence::Recurrence};
+
+enum RecurrenceClause {
+    Times(Vec<WallClockTime>),
+    Weekdays(Vec<Weekday>),
+    Start(ParsedTime),
+    Until(ParsedTime),
+    Count(u32),
+}
+
+fn parse_u32(input: &str) -> FlexitimeResult<&str, u32> {
+    map_res(digit1, |s: &str| s.parse::<u32>()).parse(input)
+}
+
+fn list_separator(input: &str) -> FlexitimeResult<&str, ()> {
+    alt((
+        value(
+            (),
+            (space0, char(','), space0, opt((tag_no_case("and"), space1))),
+        ),
+        value((), (space1, tag_no_case("and"), space1)),
+    ))
+    .parse(input)
+}
+
+fn parse_weekday_list(input: &str) -> FlexitimeResult<&str, Vec<Weekday>> {
+    separated_list1(list_separator, parse_weekday).parse(input)
+}
+
+fn parse_frequency<'a>(
+    input: &'a str,
+    parser: &FlexiParser,
+) -> FlexitimeResult<&'a str, Recurrence> {
+    alt((
+        map(
+            terminated(tag_no_case("weekday"), opt(tag_no_case("s"))),
+            |_| {
+                Recurrence::new(RelativeUnit::Weeks, 1).weekdays([
+                    Weekday::Mon,
+                    Weekday::Tue,
+                    Weekday::Wed,
+                    Weekday::Thu,
+                    Weekday::Fri,
+                ])
+            },
+        ),
+        map(
+            terminated(tag_no_case("weekend"), opt(tag_no_case("s"))),
+            |_| Recurrence::new(RelativeUnit::Weeks, 1).weekdays([Weekday::Sat, Weekday::Sun]),
+        ),
+        map(
+            (
+                opt(terminated(tag_no_case("other"), space1)),
+                parse_weekday_list,
+            ),
+            |(other, weekdays)| {
+                Recurrence::new(RelativeUnit::Weeks, if other.is_some() { 2 } else { 1 })
+                    .weekdays(weekdays)
+            },
+        ),
+        map_opt(
+            preceded((tag_no_case("other"), space1), |i| {
+                parse_unit_name_with(i, parser)
+            }),
+            |unit| Some(Recurrence::new(unit.unit, unit.amount.checked_mul(2)?)),
+        ),
+        map_opt(
+            (parse_u32, space0, |i| parse_unit_name_with(i, parser)),
+            |(interval, _, unit)| {
+                Some(Recurrence::new(
+                    unit.unit,
+                    interval.checked_mul(unit.amount)?,
+                ))
+            },
+        ),
+        map(
+            |i| parse_unit_name_with(i, parser),
+            |unit| Recurrence::new(unit.unit, unit.amount),
+        ),
+    ))
+    .parse(input)
+}
+
+fn parse_clause<'a>(
+    input: &'a str,
+    parser: &FlexiParser,
+) -> FlexitimeResult<&'a str, RecurrenceClause> {
+    alt((
+        map(
+            preceded(
+                (tag_no_case("at"), space1),
+                separated_list1(list_separator, parse_wall_clock_time),
+            ),
+            RecurrenceClause::Times,
+        ),
+        map(
+            preceded((tag_no_case("on"), space1), parse_weekday_list),
+            RecurrenceClause::Weekdays,
+        ),
+        map(
+            preceded(
+                (
+                    alt((
+                        tag_no_case("starting"),
+                        tag_no_case("beginning"),
+                        tag_no_case("from"),
+                    )),
+                    space1,
+                ),
+                |i: &'a str| parser.parse_time(i),
+            ),
+            RecurrenceClause::Start,
+        ),
+        map(
+            preceded((tag_no_case("until"), space1), |i: &'a str| {
+                parser.parse_time(i)
+            }),
+            RecurrenceClause::Until,
+        ),
+        map(
+            preceded(
+                opt((tag_no_case("for"), space1)),
+                terminated(
+                    parse_u32,
+                    (
+                        space1,
+                        alt((tag_no_case("times"), tag_no_case("occurrences"))),
+                    ),
+                ),
+            ),
+            RecurrenceClause::Count,
+        ),
+    ))
+    .parse(input)
+}
+
+pub fn parse_recurrence(input: &str) -> FlexitimeResult<&str, Recurrence> {
+    parse_recurrence_with(input, FlexiParser::shared())
+}
+
+pub fn parse_recurrence_with<'a>(
+    input: &'a str,
+    parser: &FlexiParser,
+) -> FlexitimeResult<&'a str, Recurrence> {
+    let (input, mut recurrence) = preceded((tag_no_case("every"), space1), |i: &'a str| {
+        parse_frequency(i, parser)
+    })
+    .parse(input)?;
+
+    let (input, clauses) =
+        many0(preceded(space0, |i: &'a str| parse_clause(i, parser))).parse(input)?;
+
+    for clause in clauses {
+        match clause {
+            RecurrenceClause::Times(times) => recurrence.times.extend(times),
+            RecurrenceClause::Weekdays(weekdays) => recurrence.weekdays.extend(weekdays),
+            RecurrenceClause::Start(start) => recurrence.start = Some(start),
+            RecurrenceClause::Until(until) => recurrence.until = Some(until),
+            RecurrenceClause::Count(count) => recurrence.count = Some(count),
+        }
+    }
+
+    Ok((input, recurrence))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::{
+        fixtures::anchor,
+        parser::absolute::{AbsoluteTimeBuilder, DayOffset, FlexiDate, TimePeriod},
+        resolve::ResolveOptions,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_parse_weekdays_with_time() {
+        assert_eq!(
+            parse_recurrence("every Monday at 9am"),
+            Ok((
+                "",
+                Recurrence::new(RelativeUnit::Weeks, 1)
+                    .weekdays([Weekday::Mon])
+                    .time(WallClockTime::new(9, 0, 0, Some(TimePeriod::Am)).unwrap())
+            ))
+        );
+        assert_eq!(
+            parse_recurrence("every mon, wed and fri at 10:30"),
+            Ok((
+                "",
+                Recurrence::new(RelativeUnit::Weeks, 1)
+                    .weekdays([Weekday::Mon, Weekday::Wed, Weekday::Fri])
+                    .time(WallClockTime::new(10, 30, 0, None).unwrap())
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_intervals() {
+        assert_eq!(
+            parse_recurrence("every 2 weeks"),
+            Ok(("", Recurrence::new(RelativeUnit::Weeks, 2)))
+        );
+        assert_eq!(
+            parse_recurrence("every other day"),
+            Ok(("", Recurrence::new(RelativeUnit::Days, 2)))
+        );
+        assert_eq!(
+            parse_recurrence("every hour"),
+            Ok(("", Recurrence::new(RelativeUnit::Hours, 1)))
+        );
+        assert_eq!(
+            parse_recurrence("every 3 business days"),
+            Ok(("", Recurrence::new(RelativeUnit::BusinessDays, 3)))
+        );
+    }
+
+    #[test]
+    fn test_parse_bounds() {
+        assert_eq!(
+            parse_recurrence("every weekday at 8am starting tomorrow for 10 occurrences"),
+            Ok((
+                "",
+                Recurrence::new(RelativeUnit::Weeks, 1)
+                    .weekdays([
+                        Weekday::Mon,
+                        Weekday::Tue,
+                        Weekday::Wed,
+                        Weekday::Thu,
+                        Weekday::Fri
+                    ])
+                    .time(WallClockTime::new(8, 0, 0, Some(TimePeriod::Am)).unwrap())
+                    .start(ParsedTime::Absolute(
+                        AbsoluteTimeBuilder::new()
+                            .date(FlexiDate::DayOffset(DayOffset::Fixed(1)))
+                            .build()
+                            .unwrap()
+                    ))
+                    .count(10)
+            ))
+        );
+        assert_eq!(
+            parse_recurrence("every day until 31/12/2025 for 5 times"),
+            Ok((
+                "",
+                Recurrence::new(RelativeUnit::Days, 1)
+                    .until(ParsedTime::Absolute(
+                        AbsoluteTimeBuilder::new()
+                            .date(FlexiDate::Date(
+                                NaiveDate::from_ymd_opt(2025, 12, 31).unwrap()
+                            ))
+                            .build()
+                            .unwrap()
+                    ))
+                    .count(5)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parsed_occurrences() {
+        let (_, recurrence) = parse_recurrence("every other monday at 9am").unwrap();
+        let occurrences = recurrence
+            .occurrences(anchor(), &ResolveOptions::default())
+            .unwrap()
+            .take(2)
+            .collect::<Vec<_>>();
+
+        // The week containing the anchor has already had its Monday, so the first occurrence is
+        // two weeks later
+        assert_eq!(
+            occurrences,
+            vec![
+                NaiveDate::from_ymd_opt(2025, 11, 3)
+                    .unwrap()
+                    .and_hms_opt(9, 0, 0)
+                    .unwrap(),
+                NaiveDate::from_ymd_opt(2025, 11, 17)
+                    .unwrap()
+                    .and_hms_opt(9, 0, 0)
+                    .unwrap(),
+            ]
+        );
+    }
+}
@@ -1,41 +1,44 @@
 use nom::{
     Parser,
     bytes::complete::tag_no_case,
-    character::complete::{multispace1, space1},
-    combinator::opt,
+    character::complete::{alpha1, multispace1, space1},
+    combinator::{not, opt},
     multi::separated_list1,
 };
 use suffix::Suffix;
 use time::RelativeTime;
-use units::RelativeUnit;
 
-use crate::error::FlexitimeResult;
+use crate::{error::FlexitimeResult, parser::FlexiParser};
 
 mod suffix;
 pub mod time;
 pub mod units;
 
 pub fn parse_relative_time(input: &str) -> FlexitimeResult<&str, RelativeTime> {
+    parse_relative_time_with(input, FlexiParser::shared())
+}
+
+pub fn parse_relative_time_with<'a>(
+    input: &'a str,
+    parser: &FlexiParser,
+) -> FlexitimeResult<&'a str, RelativeTime> {
+    if let (input, Some(_)) = opt((tag_no_case("now"), not(alpha1))).parse(input)? {
+        return Ok((input, RelativeTime::default()));
+    }
+
     let (input, _) = opt((tag_no_case("in"), multispace1)).parse(input)?;
 
     let (input, (units, suffix)) = (
-        separated_list1(space1, units::parse_unit),
+        separated_list1(space1, |i| units::parse_unit_with(i, parser)),
         suffix::parse_suffix,
     )
         .parse(input)?;
 
-    let mut time = RelativeTime::default();
-    for unit in units {
-        match unit.unit {
-            RelativeUnit::Seconds => time.seconds = Some(unit.amount),
-            RelativeUnit::Minutes => time.minutes = Some(unit.amount),
-            RelativeUnit::Hours => time.hours = Some(unit.amount),
-            RelativeUnit::Days => time.days = Some(unit.amount),
-            RelativeUnit::Weeks => time.weeks = Some(unit.amount),
-            RelativeUnit::Months => time.months = Some(unit.amount),
-            RelativeUnit::Years => time.years = Some(unit.amount),
-        }
-    }
+    let mut time = units
+        .into_iter()
+        .fold(RelativeTime::default(), |time, unit| {
+            time.unit(unit.unit, unit.amount)
+        });
 
     if let Some(suffix) = suffix
         && suffix == Suffix::Ago
@@ -50,6 +53,12 @@ pub fn parse_relative_time(input: &str) -> FlexitimeResult<&str, RelativeTime> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_now() {
+        assert_eq!(parse_relative_time("now"), Ok(("", RelativeTime::new())));
+        assert!(parse_relative_time("nowhere").is_err());
+    }
+
     #[test]
     fn test_parse() {
         assert_eq!(
@@ -98,6 +107,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_quarters() {
+        assert_eq!(
+            parse_relative_time("in 1 quarter"),
+            Ok(("", RelativeTime::new().quarters(1)))
+        );
+    }
+
+    #[test]
+    fn test_parse_business_days() {
+        assert_eq!(
+            parse_relative_time("in 3 business days"),
+            Ok(("", RelativeTime::new().business_days(3)))
+        );
+        assert_eq!(
+            parse_relative_time("2 working days ago"),
+            Ok(("", RelativeTime::new().business_days(2).ago()))
+        );
+    }
+
     #[test]
     fn test_parse_negative() {
         assert_eq!(
@@ -1,8 +1,12 @@
-use chrono::{Months, NaiveDateTime};
+use std::fmt;
 
-use crate::error::{FlexitimeError, FlexitimeResult};
+use chrono::{DateTime, NaiveDateTime, TimeDelta, TimeZone};
 
-#[derive(Debug, PartialEq, Default)]
+use super::units::RelativeUnit;
+use crate::resolve::{DayArithmetic, ResolveError, ResolveOptions};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone, Default)]
 pub struct RelativeTime {
     pub seconds: Option<u32>,
     pub minutes: Option<u32>,
@@ -10,10 +14,44 @@ pub struct RelativeTime {
     pub days: Option<u32>,
     pub weeks: Option<u32>,
     pub months: Option<u32>,
+    pub quarters: Option<u32>,
     pub years: Option<u32>,
+    pub business_days: Option<u32>,
     pub negative: bool,
 }
 
+impl fmt::Display for RelativeTime {
+    /// Writes the time with its units from largest to smallest, e.g. `in 2 days 3 hours` or
+    /// `5 minutes ago`. A time without any units is written as `now`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let units = [
+            (self.years, "year"),
+            (self.quarters, "quarter"),
+            (self.months, "month"),
+            (self.weeks, "week"),
+            (self.days, "day"),
+            (self.business_days, "business day"),
+            (self.hours, "hour"),
+            (self.minutes, "minute"),
+            (self.seconds, "second"),
+        ]
+        .into_iter()
+        .filter_map(|(amount, name)| {
+            amount.map(|amount| match amount {
+                1 => format!("1 {name}"),
+                amount => format!("{amount} {name}s"),
+            })
+        })
+        .collect::<Vec<_>>();
+
+        match (units.is_empty(), self.negative) {
+            (true, _) => write!(f, "now"),
+            (false, true) => write!(f, "{} ago", units.join(" ")),
+            (false, false) => write!(f, "in {}", units.join(" ")),
+        }
+    }
+}
+
 impl RelativeTime {
     pub fn new() -> Self {
         RelativeTime::default()
@@ -59,55 +97,138 @@ impl RelativeTime {
         self
     }
 
+    pub fn quarters(mut self, quarters: u32) -> Self {
+        self.quarters = Some(quarters);
+        self
+    }
+
     pub fn years(mut self, years: u32) -> Self {
         self.years = Some(years);
         self
     }
 
-    pub fn to_chrono(&self, mut base_time: NaiveDateTime) -> NaiveDateTime {
+    pub fn business_days(mut self, business_days: u32) -> Self {
+        self.business_days = Some(business_days);
+        self
+    }
+
+    /// Sets the amount of the given unit
+    pub fn unit(mut self, unit: RelativeUnit, amount: u32) -> Self {
+        let field = match unit {
+            RelativeUnit::Seconds => &mut self.seconds,
+            RelativeUnit::Minutes => &mut self.minutes,
+            RelativeUnit::Hours => &mut self.hours,
+            RelativeUnit::Days => &mut self.days,
+            RelativeUnit::BusinessDays => &mut self.business_days,
+            RelativeUnit::Weeks => &mut self.weeks,
+            RelativeUnit::Months => &mut self.months,
+            RelativeUnit::Quarters => &mut self.quarters,
+            RelativeUnit::Years => &mut self.years,
+        };
+        *field = Some(amount);
+        self
+    }
+
+    pub fn resolve(
+        &self,
+        mut base_time: NaiveDateTime,
+        options: &ResolveOptions,
+    ) -> Result<NaiveDateTime, ResolveError> {
         let sign = if self.negative { -1 } else { 1 };
 
-        if let Some(seconds) = self.seconds {
-            base_time += chrono::Duration::seconds((seconds as i64) * sign);
-        }
-        if let Some(minutes) = self.minutes {
-            base_time += chrono::Duration::minutes((minutes as i64) * sign);
-        }
-        if let Some(hours) = self.hours {
-            base_time += chrono::Duration::hours((hours as i64) * sign);
+        let fixed = [
+            self.seconds
+                .map(|seconds| TimeDelta::seconds(seconds.into())),
+            self.minutes
+                .map(|minutes| TimeDelta::minutes(minutes.into())),
+            self.hours.map(|hours| TimeDelta::hours(hours.into())),
+            self.days.map(|days| TimeDelta::days(days.into())),
+            self.weeks.map(|weeks| TimeDelta::weeks(weeks.into())),
+        ];
+        for delta in fixed.into_iter().flatten() {
+            base_time = base_time
+                .checked_add_signed(delta * sign)
+                .ok_or(ResolveError::OutOfRange)?;
         }
-        if let Some(days) = self.days {
-            base_time += chrono::Duration::days((days as i64) * sign);
-        }
-        if let Some(weeks) = self.weeks {
-            base_time += chrono::Duration::weeks((weeks as i64) * sign);
+
+        // Months, quarters and years are added together, so the month arithmetic only applies
+        // to the date they land on
+        let months = [
+            self.months.map(Some),
+            self.quarters.map(|quarters| quarters.checked_mul(3)),
+            self.years.map(|years| years.checked_mul(12)),
+        ];
+        if months.iter().any(Option::is_some) {
+            let months = months
+                .into_iter()
+                .flatten()
+                .try_fold(0u32, |total, months| total.checked_add(months?))
+                .and_then(|months| i32::try_from(months).ok())
+                .and_then(|months| months.checked_mul(sign))
+                .ok_or(ResolveError::OutOfRange)?;
+            let date = options.add_months(base_time.date(), months)?;
+            base_time = date.and_time(base_time.time());
         }
-        if let Some(months) = self.months {
-            let months = Months::new(months);
-            base_time = if self.negative {
-                base_time.checked_sub_months(months)
-            } else {
-                base_time.checked_add_months(months)
-            }
-            .unwrap();
+
+        if let Some(business_days) = self.business_days {
+            let business_days = i32::try_from(business_days)
+                .ok()
+                .and_then(|days| days.checked_mul(sign))
+                .ok_or(ResolveError::OutOfRange)?;
+            let date = options.add_business_days(base_time.date(), business_days)?;
+            base_time = date.and_time(base_time.time());
         }
-        if let Some(years) = self.years {
-            let months = Months::new(years * 12);
-            base_time = if self.negative {
-                base_time.checked_sub_months(months)
-            } else {
-                base_time.checked_add_months(months)
-            }
-            .unwrap();
+
+        Ok(base_time)
+    }
+
+    /// Resolves the time relative to `anchor` in its time zone. Hours, minutes and seconds are
+    /// always elapsed time, whereas days and weeks follow [`ResolveOptions::day_arithmetic`].
+    pub fn resolve_in<Tz: TimeZone>(
+        &self,
+        anchor: &DateTime<Tz>,
+        options: &ResolveOptions,
+    ) -> Result<DateTime<Tz>, ResolveError> {
+        let elapsed = |amount: Option<u32>, unit: fn(i64) -> TimeDelta| {
+            amount.map_or(TimeDelta::zero(), |amount| unit(amount.into()))
+        };
+        let mut calendar = self.clone();
+        let mut exact = elapsed(calendar.seconds.take(), TimeDelta::seconds)
+            + elapsed(calendar.minutes.take(), TimeDelta::minutes)
+            + elapsed(calendar.hours.take(), TimeDelta::hours);
+        if options.day_arithmetic == DayArithmetic::Exact {
+            exact = exact
+                + elapsed(calendar.days.take(), TimeDelta::days)
+                + elapsed(calendar.weeks.take(), TimeDelta::weeks);
         }
 
-        base_time
+        // Localizing the anchor's own local time could move it to the other side of a fold
+        let local = calendar.resolve(anchor.naive_local(), options)?;
+        let time = if local == anchor.naive_local() {
+            anchor.clone()
+        } else {
+            options.localize(&anchor.timezone(), local)?
+        };
+
+        let exact = if self.negative { -exact } else { exact };
+        time.checked_add_signed(exact)
+            .ok_or(ResolveError::OutOfRange)
+    }
+
+    /// Resolves the time relative to `base_time` with the default [`ResolveOptions`]
+    pub fn to_chrono(&self, base_time: NaiveDateTime) -> Result<NaiveDateTime, ResolveError> {
+        self.resolve(base_time, &ResolveOptions::default())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use chrono::{Duration, Utc};
+    use chrono::{Duration, Month, Months, NaiveDate, Utc};
+
+    use crate::{
+        holiday::{Holiday, HolidayRule, HolidaySet},
+        resolve::MonthArithmetic,
+    };
 
     use super::*;
 
@@ -123,7 +244,7 @@ mod tests {
             .hours(5)
             .minutes(6)
             .seconds(7)
-            .to_chrono(base_time.clone());
+            .to_chrono(base_time);
 
         let mut new_time = base_time
             + Duration::seconds(7)
@@ -131,10 +252,9 @@ mod tests {
             + Duration::hours(5)
             + Duration::days(4)
             + Duration::weeks(3);
-        new_time = new_time.checked_add_months(Months::new(2)).unwrap();
-        new_time = new_time.checked_add_months(Months::new(1 * 12)).unwrap();
+        new_time = new_time.checked_add_months(Months::new(14)).unwrap();
 
-        assert_eq!(time, new_time)
+        assert_eq!(time, Ok(new_time))
     }
 
     #[test]
@@ -150,7 +270,7 @@ mod tests {
             .minutes(6)
             .seconds(7)
             .ago()
-            .to_chrono(base_time.clone());
+            .to_chrono(base_time);
 
         let mut new_time = base_time
             - Duration::seconds(7)
@@ -158,9 +278,122 @@ mod tests {
             - Duration::hours(5)
             - Duration::days(4)
             - Duration::weeks(3);
-        new_time = new_time.checked_sub_months(Months::new(2)).unwrap();
-        new_time = new_time.checked_sub_months(Months::new(1 * 12)).unwrap();
+        new_time = new_time.checked_sub_months(Months::new(14)).unwrap();
+
+        assert_eq!(time, Ok(new_time))
+    }
 
-        assert_eq!(time, new_time)
+    #[test]
+    fn test_business_days_skip_weekends_and_holidays() {
+        // A Thursday
+        let base_time = NaiveDate::from_ymd_opt(2025, 12, 18)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap();
+        let options = ResolveOptions::new().holidays(HolidaySet::new().holiday(Holiday::new(
+            "christmas",
+            HolidayRule::Fixed {
+                month: Month::December,
+                day: 25,
+            },
+        )));
+
+        assert_eq!(
+            RelativeTime::new()
+                .business_days(5)
+                .resolve(base_time, &options),
+            Ok(NaiveDate::from_ymd_opt(2025, 12, 26)
+                .unwrap()
+                .and_hms_opt(10, 0, 0)
+                .unwrap())
+        );
+        assert_eq!(
+            RelativeTime::new()
+                .business_days(2)
+                .ago()
+                .resolve(base_time, &options),
+            Ok(NaiveDate::from_ymd_opt(2025, 12, 16)
+                .unwrap()
+                .and_hms_opt(10, 0, 0)
+                .unwrap())
+        );
+
+        assert_eq!(
+            RelativeTime::new()
+                .business_days(2_147_483_648)
+                .ago()
+                .resolve(base_time, &options),
+            Err(ResolveError::OutOfRange)
+        );
+        assert_eq!(
+            RelativeTime::new()
+                .business_days(3_000_000_000)
+                .resolve(base_time, &options),
+            Err(ResolveError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_month_arithmetic() {
+        let leap_day = NaiveDate::from_ymd_opt(2024, 2, 29)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+        let resolve = |time: RelativeTime, arithmetic| {
+            time.resolve(
+                leap_day,
+                &ResolveOptions::new().month_arithmetic(arithmetic),
+            )
+            .map(|time| time.to_string())
+        };
+
+        assert_eq!(
+            resolve(RelativeTime::new().years(1), MonthArithmetic::Clamp),
+            Ok("2025-02-28 09:00:00".to_string())
+        );
+        assert_eq!(
+            resolve(RelativeTime::new().years(1), MonthArithmetic::Overflow),
+            Ok("2025-03-01 09:00:00".to_string())
+        );
+        assert_eq!(
+            resolve(
+                RelativeTime::new().months(1).ago(),
+                MonthArithmetic::PreserveEndOfMonth
+            ),
+            Ok("2024-01-31 09:00:00".to_string())
+        );
+        assert_eq!(
+            resolve(RelativeTime::new().quarters(4), MonthArithmetic::Error),
+            Err(ResolveError::NoSuchDay {
+                year: 2025,
+                month: 2,
+                day: 29
+            })
+        );
+
+        // The policy applies to where the months land rather than to each unit in turn
+        let end_of_january = NaiveDate::from_ymd_opt(2025, 1, 31)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+        for arithmetic in [
+            MonthArithmetic::Clamp,
+            MonthArithmetic::Overflow,
+            MonthArithmetic::PreserveEndOfMonth,
+            MonthArithmetic::Error,
+        ] {
+            let options = ResolveOptions::new().month_arithmetic(arithmetic);
+            assert_eq!(
+                RelativeTime::new()
+                    .months(1)
+                    .quarters(1)
+                    .resolve(end_of_january, &options),
+                Ok(NaiveDate::from_ymd_opt(2025, 5, 31)
+                    .unwrap()
+                    .and_hms_opt(9, 0, 0)
+                    .unwrap()),
+                "{arithmetic:?}"
+            );
+        }
     }
 }
@@ -2,12 +2,13 @@ use std::str::FromStr;
 
 use nom::{
     Parser,
-    character::complete::{alpha1, digit1, space0},
-    combinator::map_res,
+    branch::alt,
+    bytes::complete::tag_no_case,
+    character::complete::{alpha1, digit1, space0, space1},
+    combinator::{map, map_res, recognize},
 };
-use strum_macros::EnumString;
 
-use crate::error::FlexitimeResult;
+use crate::{error::FlexitimeResult, parser::FlexiParser};
 
 #[derive(Debug, Clone, PartialEq, thiserror::Error)]
 pub enum RelativeUnitsError {
@@ -15,64 +16,81 @@ pub enum RelativeUnitsError {
     UnknownUnit(String),
 }
 
-#[derive(Debug, Clone, PartialEq, EnumString)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum RelativeUnit {
-    #[strum(
-        serialize = "seconds",
-        serialize = "second",
-        serialize = "secs",
-        serialize = "sec",
-        serialize = "s"
-    )]
     Seconds,
-
-    #[strum(
-        serialize = "minutes",
-        serialize = "minute",
-        serialize = "mins",
-        serialize = "min",
-        serialize = "m"
-    )]
     Minutes,
-
-    #[strum(
-        serialize = "hours",
-        serialize = "hour",
-        serialize = "hrs",
-        serialize = "hr",
-        serialize = "h"
-    )]
     Hours,
-
-    #[strum(serialize = "days", serialize = "day", serialize = "d")]
     Days,
-
-    #[strum(
-        serialize = "weeks",
-        serialize = "week",
-        serialize = "wks",
-        serialize = "wk",
-        serialize = "w"
-    )]
+    BusinessDays,
     Weeks,
-
-    #[strum(
-        serialize = "months",
-        serialize = "month",
-        serialize = "mos",
-        serialize = "mo"
-    )]
     Months,
-
-    #[strum(
-        serialize = "years",
-        serialize = "year",
-        serialize = "yr",
-        serialize = "y"
-    )]
+    Quarters,
     Years,
 }
 
+impl RelativeUnit {
+    /// The English names of each unit, including their abbreviations. This is the one list of
+    /// English unit names, shared by the parser, [`Locale::ENGLISH`](crate::parser::Locale),
+    /// typo suggestions and completion.
+    pub const NAMES: &'static [(&'static str, RelativeUnit)] = &[
+        ("seconds", RelativeUnit::Seconds),
+        ("second", RelativeUnit::Seconds),
+        ("secs", RelativeUnit::Seconds),
+        ("sec", RelativeUnit::Seconds),
+        ("s", RelativeUnit::Seconds),
+        ("minutes", RelativeUnit::Minutes),
+        ("minute", RelativeUnit::Minutes),
+        ("mins", RelativeUnit::Minutes),
+        ("min", RelativeUnit::Minutes),
+        ("m", RelativeUnit::Minutes),
+        ("hours", RelativeUnit::Hours),
+        ("hour", RelativeUnit::Hours),
+        ("hrs", RelativeUnit::Hours),
+        ("hr", RelativeUnit::Hours),
+        ("h", RelativeUnit::Hours),
+        ("days", RelativeUnit::Days),
+        ("day", RelativeUnit::Days),
+        ("d", RelativeUnit::Days),
+        ("business days", RelativeUnit::BusinessDays),
+        ("business day", RelativeUnit::BusinessDays),
+        ("working days", RelativeUnit::BusinessDays),
+        ("working day", RelativeUnit::BusinessDays),
+        ("workdays", RelativeUnit::BusinessDays),
+        ("workday", RelativeUnit::BusinessDays),
+        ("weeks", RelativeUnit::Weeks),
+        ("week", RelativeUnit::Weeks),
+        ("wks", RelativeUnit::Weeks),
+        ("wk", RelativeUnit::Weeks),
+        ("w", RelativeUnit::Weeks),
+        ("months", RelativeUnit::Months),
+        ("month", RelativeUnit::Months),
+        ("mos", RelativeUnit::Months),
+        ("mo", RelativeUnit::Months),
+        ("quarters", RelativeUnit::Quarters),
+        ("quarter", RelativeUnit::Quarters),
+        ("qtrs", RelativeUnit::Quarters),
+        ("qtr", RelativeUnit::Quarters),
+        ("q", RelativeUnit::Quarters),
+        ("years", RelativeUnit::Years),
+        ("year", RelativeUnit::Years),
+        ("yr", RelativeUnit::Years),
+        ("y", RelativeUnit::Years),
+    ];
+}
+
+impl FromStr for RelativeUnit {
+    type Err = RelativeUnitsError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        RelativeUnit::NAMES
+            .iter()
+            .find(|(unit_name, _)| *unit_name == name)
+            .map(|(_, unit)| unit.clone())
+            .ok_or_else(|| RelativeUnitsError::UnknownUnit(name.to_string()))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ParsedUnit {
     pub unit: RelativeUnit,
@@ -83,19 +101,55 @@ fn parse_u32(input: &str) -> FlexitimeResult<&str, u32> {
     map_res(digit1, |s: &str| s.parse::<u32>()).parse(input)
 }
 
+/// Parses the name of a unit, such as `hours` or `business days`
+pub fn parse_unit_name(input: &str) -> FlexitimeResult<&str, RelativeUnit> {
+    let (input, token) = alt((
+        recognize((
+            alt((tag_no_case("business"), tag_no_case("working"))),
+            space1,
+            alpha1,
+        )),
+        alpha1,
+    ))
+    .parse(input)?;
+    let token = token.split_whitespace().collect::<Vec<_>>().join(" ");
+    let unit = RelativeUnit::from_str(&token)
+        .map_err(|_err| nom::Err::Error(RelativeUnitsError::UnknownUnit(token).into()))?;
+
+    Ok((input, unit))
+}
+
 pub fn parse_unit(input: &str) -> FlexitimeResult<&str, ParsedUnit> {
     let (input, amount) = parse_u32(input)?;
 
     let (input, _) = space0(input)?;
 
-    let (input, token) = alpha1(input)?;
-    let unit = RelativeUnit::from_str(token).map_err(|_err| {
-        nom::Err::Error(RelativeUnitsError::UnknownUnit(token.to_string()).into())
-    })?;
+    let (input, unit) = parse_unit_name(input)?;
 
     Ok((input, ParsedUnit { amount, unit }))
 }
 
+/// Parses the name of a unit, consulting the parser's vocabulary first. Names for a number of
+/// units, such as `sprint`, give the number of units they stand for.
+pub fn parse_unit_name_with<'a>(
+    input: &'a str,
+    parser: &FlexiParser,
+) -> FlexitimeResult<&'a str, ParsedUnit> {
+    alt((
+        |i| parser.vocabulary.parse_unit_name(i),
+        map(parse_unit_name, |unit| ParsedUnit { unit, amount: 1 }),
+    ))
+    .parse(input)
+}
+
+/// Parses an amount of a unit, consulting the parser's vocabulary first
+pub fn parse_unit_with<'a>(
+    input: &'a str,
+    parser: &FlexiParser,
+) -> FlexitimeResult<&'a str, ParsedUnit> {
+    alt((|i| parser.vocabulary.parse_unit(i), parse_unit)).parse(input)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,6 +196,16 @@ mod tests {
                 }
             ))
         );
+        assert_eq!(
+            parse_unit("2 quarters"),
+            Ok((
+                "",
+                ParsedUnit {
+                    amount: 2,
+                    unit: RelativeUnit::Quarters
+                }
+            ))
+        );
         assert_eq!(
             parse_unit("5y"),
             Ok((
@@ -153,4 +217,21 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn test_english_locale_shares_unit_names() {
+        for (name, unit) in crate::parser::Locale::ENGLISH.units {
+            assert_eq!(parse_unit_name(name), Ok(("", unit.clone())), "{name}");
+        }
+        assert_eq!(
+            parse_unit("3 wks"),
+            Ok((
+                "",
+                ParsedUnit {
+                    amount: 3,
+                    unit: RelativeUnit::Weeks
+                }
+            ))
+        );
+    }
 }
@@ -1,18 +1,159 @@
-use chrono::{NaiveDateTime, Utc};
+use std::fmt;
+
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 
 use super::{absolute::AbsoluteTime, relative::time::RelativeTime};
+use crate::resolve::{ResolveError, ResolveOptions};
 
-#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ParsedTime {
     Relative(RelativeTime),
     Absolute(AbsoluteTime),
 }
 
 impl ParsedTime {
-    pub fn to_chrono(&self) -> NaiveDateTime {
+    pub fn resolve(
+        &self,
+        anchor: NaiveDateTime,
+        options: &ResolveOptions,
+    ) -> Result<NaiveDateTime, ResolveError> {
+        match self {
+            ParsedTime::Relative(time) => time.resolve(anchor, options),
+            ParsedTime::Absolute(time) => time.resolve(anchor, options),
+        }
+    }
+
+    /// Resolves the time relative to `anchor` in its time zone, so that changes of the clocks
+    /// are accounted for
+    pub fn resolve_in<Tz: TimeZone>(
+        &self,
+        anchor: &DateTime<Tz>,
+        options: &ResolveOptions,
+    ) -> Result<DateTime<Tz>, ResolveError> {
+        match self {
+            ParsedTime::Relative(time) => time.resolve_in(anchor, options),
+            ParsedTime::Absolute(time) => time.resolve_in(anchor, options),
+        }
+    }
+
+    /// Resolves the time against the current time with the default [`ResolveOptions`]
+    pub fn to_chrono(&self) -> Result<NaiveDateTime, ResolveError> {
+        self.resolve(Utc::now().naive_utc(), &ResolveOptions::default())
+    }
+}
+
+impl fmt::Display for ParsedTime {
+    /// Writes the time in a canonical form, such that parsing the output with
+    /// [`crate::parse_timestring`] gives back an equal value
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            ParsedTime::Relative(time) => time.to_chrono(Utc::now().naive_utc()),
-            ParsedTime::Absolute(time) => time.to_chrono(),
+            ParsedTime::Relative(time) => write!(f, "{time}"),
+            ParsedTime::Absolute(time) => write!(f, "{time}"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use chrono_tz::America::New_York;
+
+    use super::*;
+    use crate::{
+        parser::parse_timestring,
+        resolve::{DayArithmetic, GapPolicy},
+    };
+
+    fn resolve(input: &str, anchor: &DateTime<chrono_tz::Tz>, options: &ResolveOptions) -> String {
+        let (_, time) = parse_timestring(input).unwrap();
+        time.resolve_in(anchor, options).unwrap().to_rfc3339()
+    }
+
+    #[test]
+    fn test_resolve_across_spring_forward() {
+        // The day before New York skips from 2am to 3am
+        let anchor = New_York
+            .from_local_datetime(
+                &NaiveDate::from_ymd_opt(2025, 3, 8)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap(),
+            )
+            .unwrap();
+        let options = ResolveOptions::new();
+
+        assert_eq!(
+            resolve("tomorrow 2:30am", &anchor, &options),
+            "2025-03-09T03:30:00-04:00"
+        );
+        assert_eq!(
+            resolve(
+                "tomorrow 2:30am",
+                &anchor,
+                &options.clone().gap_policy(GapPolicy::ShiftForward)
+            ),
+            "2025-03-09T03:00:00-04:00"
+        );
+        assert!(matches!(
+            parse_timestring("tomorrow 2:30am")
+                .unwrap()
+                .1
+                .resolve_in(&anchor, &options.clone().gap_policy(GapPolicy::Error)),
+            Err(ResolveError::NonexistentLocalTime(_))
+        ));
+
+        assert_eq!(
+            resolve("in 1 day", &anchor, &options),
+            "2025-03-09T12:00:00-04:00"
+        );
+        assert_eq!(
+            resolve(
+                "in 1 day",
+                &anchor,
+                &options.clone().day_arithmetic(DayArithmetic::Exact)
+            ),
+            "2025-03-09T13:00:00-04:00"
+        );
+        assert_eq!(
+            resolve("in 24 hours", &anchor, &options),
+            "2025-03-09T13:00:00-04:00"
+        );
+    }
+
+    #[test]
+    fn test_resolve_across_fall_back() {
+        // The day New York repeats 1am to 2am, after the clocks have gone back
+        let anchor = New_York
+            .from_local_datetime(
+                &NaiveDate::from_ymd_opt(2025, 11, 2)
+                    .unwrap()
+                    .and_hms_opt(1, 30, 0)
+                    .unwrap(),
+            )
+            .latest()
+            .unwrap();
+        let options = ResolveOptions::new();
+
+        assert_eq!(
+            resolve("1 hour ago", &anchor, &options),
+            "2025-11-02T01:30:00-04:00"
+        );
+        assert_eq!(
+            resolve("in 0 days", &anchor, &options),
+            "2025-11-02T01:30:00-05:00"
+        );
+        assert_eq!(
+            resolve("1 day ago", &anchor, &options),
+            "2025-11-01T01:30:00-04:00"
+        );
+        assert_eq!(
+            resolve(
+                "1 week ago",
+                &anchor,
+                &options.clone().day_arithmetic(DayArithmetic::Exact)
+            ),
+            "2025-10-26T02:30:00-04:00"
+        );
+    }
+}
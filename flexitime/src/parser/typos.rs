@@ -0,0 +1,321 @@
+use std::{ops::Range, str::FromStr};
+
+use super::{FlexiParser, relative::units::RelativeUnit};
+use crate::error::{FlexitimeError, FlexitimeResult};
+
+/// How a parser treats words which are a small typo away from a word it knows, such as
+/// `tommorow` or `wednsday`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Typos {
+    /// Fail with the usual error
+    Reject,
+    /// Fail with a [`Suggestion`] of the word that was probably meant
+    #[default]
+    Suggest,
+    /// Parse the input as though the word that was probably meant had been typed
+    Correct,
+}
+
+/// A word which was not understood, along with the known word closest to it
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Unknown word '{word}', did you mean '{suggestion}'?")]
+pub struct Suggestion {
+    pub word: String,
+    /// The byte range of the word within the input
+    pub span: Range<usize>,
+    pub suggestion: String,
+}
+
+/// The number of edits allowed between a word and the word it is taken to mean. Short words are
+/// left alone, as too many ordinary words are a single edit away from them.
+fn max_distance(len: usize) -> usize {
+    match len {
+        0..4 => 0,
+        4..6 => 1,
+        _ => 2,
+    }
+}
+
+/// The number of insertions, deletions, substitutions and swaps of adjacent characters needed
+/// to turn `a` into `b`
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut before = vec![0; b.len() + 1];
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        current[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before[j - 2] + 1);
+            }
+        }
+
+        std::mem::swap(&mut before, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// The byte ranges of each word in `input`
+fn words(input: &str) -> Vec<Range<usize>> {
+    let mut words = Vec::new();
+    let mut start = None;
+
+    for (index, c) in input.char_indices().chain([(input.len(), ' ')]) {
+        match (start, c.is_alphabetic()) {
+            (None, true) => start = Some(index),
+            (Some(from), false) => {
+                words.push(from..index);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    words
+}
+
+/// The words a parser knows: day names, weekdays, months, units and its vocabulary
+fn keywords(parser: &FlexiParser) -> Vec<&str> {
+    let locale = &parser.locale;
+
+    locale
+        .today
+        .iter()
+        .chain(locale.tomorrow)
+        .chain(locale.yesterday)
+        .chain(locale.weekdays.iter().copied().flatten())
+        .chain(locale.months.iter().copied().flatten())
+        .copied()
+        .chain(
+            locale
+                .units
+                .iter()
+                .flat_map(|(name, _)| name.split_whitespace()),
+        )
+        .chain(parser.vocabulary.names())
+        .collect()
+}
+
+/// Finds each word in `input` which is not known, but is close to a word which is
+fn find_typos(input: &str, parser: &FlexiParser) -> Vec<Suggestion> {
+    let keywords = keywords(parser)
+        .into_iter()
+        .map(|keyword| (keyword, keyword.to_lowercase().chars().collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+
+    words(input)
+        .into_iter()
+        .filter_map(|span| {
+            let word = &input[span.clone()];
+            let lowercase = word.to_lowercase();
+            let chars = lowercase.chars().collect::<Vec<_>>();
+            let max = max_distance(chars.len());
+
+            let known = RelativeUnit::from_str(&lowercase).is_ok()
+                || keywords.iter().any(|(_, keyword)| *keyword == chars);
+            if max == 0 || known {
+                return None;
+            }
+
+            keywords
+                .iter()
+                .filter(|(_, keyword)| keyword.len().abs_diff(chars.len()) <= max)
+                .map(|(name, keyword)| (edit_distance(&chars, keyword), name))
+                .filter(|(distance, _)| *distance <= max)
+                .min_by_key(|(distance, _)| *distance)
+                .map(|(_, name)| Suggestion {
+                    word: word.to_string(),
+                    span,
+                    suggestion: name.to_string(),
+                })
+        })
+        .collect()
+}
+
+/// Maps a byte offset in the corrected input back to the original input, unless it falls within
+/// a corrected word
+fn original_offset(offset: usize, corrections: &[Suggestion]) -> Option<usize> {
+    let mut growth = 0isize;
+
+    for correction in corrections {
+        let start = correction.span.start.checked_add_signed(growth)?;
+        if offset <= start {
+            break;
+        }
+        if offset < start + correction.suggestion.len() {
+            return None;
+        }
+        growth += correction.suggestion.len() as isize - correction.word.len() as isize;
+    }
+
+    offset.checked_add_signed(-growth)
+}
+
+/// Whether an error means that the input was not understood, rather than understood but invalid
+fn is_unrecognised<I>(err: &nom::Err<FlexitimeError<I>>) -> bool {
+    matches!(
+        err,
+        nom::Err::Error(
+            FlexitimeError::Nom(..)
+                | FlexitimeError::RelativeUnits(_)
+                | FlexitimeError::DayOffsetParse
+        ) | nom::Err::Failure(
+            FlexitimeError::Nom(..)
+                | FlexitimeError::RelativeUnits(_)
+                | FlexitimeError::DayOffsetParse
+        )
+    )
+}
+
+impl FlexiParser {
+    /// Runs `parse`, treating typos in `input` according to the parser's [`Typos`] policy
+    pub(crate) fn parse_with_typos<'a, T>(
+        &self,
+        input: &'a str,
+        parse: impl for<'b> Fn(&'b str) -> FlexitimeResult<&'b str, T>,
+    ) -> FlexitimeResult<&'a str, T> {
+        let result = parse(input);
+        let retry = match (&result, self.typos) {
+            (_, Typos::Reject) => false,
+            (Err(err), _) => is_unrecognised(err),
+            (Ok((rest, _)), Typos::Correct) => !rest.trim().is_empty(),
+            (Ok(_), Typos::Suggest) => false,
+        };
+        if !retry {
+            return result;
+        }
+
+        let typos = find_typos(input, self);
+        let Some(first) = typos.first() else {
+            return result;
+        };
+        let suggestion = || nom::Err::Error(FlexitimeError::from(first.clone()));
+
+        if self.typos == Typos::Suggest {
+            return Err(suggestion());
+        }
+
+        let mut corrected = String::with_capacity(input.len());
+        let mut end = 0;
+        for typo in &typos {
+            corrected.push_str(&input[end..typo.span.start]);
+            corrected.push_str(&typo.suggestion);
+            end = typo.span.end;
+        }
+        corrected.push_str(&input[end..]);
+
+        let consumed = match &result {
+            Ok((rest, _)) => input.len() - rest.len(),
+            Err(_) => 0,
+        };
+        if let Ok((rest, value)) = parse(&corrected)
+            && let Some(offset) = original_offset(corrected.len() - rest.len(), &typos)
+            && offset > consumed
+        {
+            return Ok((&input[offset..], value));
+        }
+
+        result.map_err(|_err| suggestion())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{Vocabulary, parse_timestring};
+
+    fn suggestion(input: &str) -> Option<String> {
+        match parse_timestring(input) {
+            Err(nom::Err::Error(FlexitimeError::Suggestion(suggestion))) => {
+                Some(suggestion.suggestion)
+            }
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        let distance = |a: &str, b: &str| {
+            edit_distance(
+                &a.chars().collect::<Vec<_>>(),
+                &b.chars().collect::<Vec<_>>(),
+            )
+        };
+
+        assert_eq!(distance("tommorow", "tomorrow"), 2);
+        assert_eq!(distance("fridya", "friday"), 1);
+        assert_eq!(distance("minuts", "minutes"), 1);
+        assert_eq!(distance("", "day"), 3);
+    }
+
+    #[test]
+    fn test_suggestions() {
+        assert_eq!(suggestion("tommorow 5pm").as_deref(), Some("tomorrow"));
+        assert_eq!(suggestion("next wednsday").as_deref(), Some("wednesday"));
+        assert_eq!(suggestion("in 3 minuts").as_deref(), Some("minutes"));
+        assert_eq!(suggestion("Septmber 2026").as_deref(), Some("september"));
+        assert_eq!(suggestion("whenever"), None);
+
+        let Err(nom::Err::Error(FlexitimeError::Suggestion(suggestion))) =
+            parse_timestring("next thrusday at 5pm")
+        else {
+            panic!("Expected a suggestion");
+        };
+        assert_eq!(suggestion.word, "thrusday");
+        assert_eq!(suggestion.span, 5..13);
+        assert_eq!(
+            suggestion.to_string(),
+            "Unknown word 'thrusday', did you mean 'thursday'?"
+        );
+    }
+
+    #[test]
+    fn test_correct() {
+        let parser = FlexiParser::new().typos(Typos::Correct);
+        let correct = |input| parser.parse(input).unwrap();
+
+        assert_eq!(
+            correct("tommorow 5pm"),
+            ("", parse_timestring("tomorrow 5pm").unwrap().1)
+        );
+        assert_eq!(
+            correct("5pm tommorow"),
+            ("", parse_timestring("5pm tomorrow").unwrap().1)
+        );
+        assert_eq!(
+            correct("3 minuts ago"),
+            ("", parse_timestring("3 minutes ago").unwrap().1)
+        );
+        assert_eq!(
+            correct("fridya and more"),
+            ("and more", parse_timestring("friday").unwrap().1)
+        );
+
+        let (_, duration) = parser.parse_duration("2 hourz 5 minuts").unwrap();
+        assert_eq!(duration.to_std().as_secs(), 2 * 60 * 60 + 5 * 60);
+
+        let parser =
+            parser.vocabulary(Vocabulary::new().duration("sprint", RelativeUnit::Weeks, 2));
+        assert_eq!(
+            parser.parse("in 2 sprnts").unwrap().1,
+            parser.parse("in 2 sprints").unwrap().1
+        );
+    }
+
+    #[test]
+    fn test_reject() {
+        let parser = FlexiParser::new().typos(Typos::Reject);
+        assert!(matches!(
+            parser.parse("tommorow"),
+            Err(nom::Err::Error(FlexitimeError::Nom(..)))
+        ));
+    }
+}
@@ -0,0 +1,215 @@
+use nom::{
+    Parser,
+    character::complete::{digit1, space0},
+    combinator::map_res,
+    error::{ErrorKind, ParseError},
+};
+
+use super::{
+    absolute::{FlexiDate, WallClockTime},
+    locale::strip_word,
+    relative::units::{ParsedUnit, RelativeUnit},
+};
+use crate::error::{FlexitimeError, FlexitimeResult};
+
+/// Words added to a parser at runtime, such as company jargon. Entries are matched ignoring case
+/// and are consulted before the built-in words.
+///
+/// ```
+/// use flexitime::{
+///     FlexiParser,
+///     parser::{Vocabulary, absolute::WallClockTime, relative::units::RelativeUnit},
+/// };
+///
+/// let parser = FlexiParser::new().vocabulary(
+///     Vocabulary::new()
+///         .time("standup", WallClockTime::new(9, 30, 0, None).unwrap())
+///         .duration("sprint", RelativeUnit::Weeks, 2),
+/// );
+///
+/// assert!(parser.parse("standup tomorrow").is_ok());
+/// assert!(parser.parse("in 2 sprints").is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Vocabulary {
+    /// Names for a number of units, which may also be written in the plural
+    pub(crate) units: Vec<(String, ParsedUnit)>,
+    pub(crate) times: Vec<(String, WallClockTime)>,
+    pub(crate) dates: Vec<(String, FlexiDate)>,
+}
+
+/// Finds the longest name at the start of `input`, optionally allowing it to end in an `s`
+fn find<'a, 'v, T>(
+    input: &'a str,
+    entries: &'v [(String, T)],
+    plurals: bool,
+) -> FlexitimeResult<&'a str, &'v T> {
+    entries
+        .iter()
+        .filter_map(|(name, value)| {
+            strip_word(input, name)
+                .or_else(|| {
+                    plurals
+                        .then(|| strip_word(input, &format!("{name}s")))
+                        .flatten()
+                })
+                .map(|rest| (rest, value))
+        })
+        .min_by_key(|(rest, _)| rest.len())
+        .ok_or_else(|| nom::Err::Error(FlexitimeError::from_error_kind(input, ErrorKind::Tag)))
+}
+
+impl Vocabulary {
+    pub fn new() -> Self {
+        Vocabulary::default()
+    }
+
+    /// Adds another name for a unit, e.g. `sol` for days
+    pub fn unit(self, name: impl Into<String>, unit: RelativeUnit) -> Self {
+        self.duration(name, unit, 1)
+    }
+
+    /// Adds a name for a number of units, e.g. `sprint` for 2 weeks. The name can be used
+    /// anywhere a unit can, so `in 3 sprints` is 6 weeks away.
+    pub fn duration(mut self, name: impl Into<String>, unit: RelativeUnit, amount: u32) -> Self {
+        self.units.push((name.into(), ParsedUnit { unit, amount }));
+        self
+    }
+
+    /// Adds a name for a time of day, e.g. `standup` for 09:30
+    pub fn time(mut self, name: impl Into<String>, time: WallClockTime) -> Self {
+        self.times.push((name.into(), time));
+        self
+    }
+
+    /// Adds a name for a date, e.g. `eow` for the end of the week
+    pub fn date(mut self, name: impl Into<String>, date: FlexiDate) -> Self {
+        self.dates.push((name.into(), date));
+        self
+    }
+
+    /// Every name in the vocabulary
+    pub(crate) fn names(&self) -> impl Iterator<Item = &str> {
+        self.units
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .chain(self.times.iter().map(|(name, _)| name.as_str()))
+            .chain(self.dates.iter().map(|(name, _)| name.as_str()))
+    }
+
+    /// Parses the name of a unit, giving the number of units it stands for
+    pub(crate) fn parse_unit_name<'a>(
+        &self,
+        input: &'a str,
+    ) -> FlexitimeResult<&'a str, ParsedUnit> {
+        find(input, &self.units, true).map(|(rest, unit)| (rest, unit.clone()))
+    }
+
+    /// Parses an amount of a named unit, such as `2 sprints`
+    pub(crate) fn parse_unit<'a>(&self, input: &'a str) -> FlexitimeResult<&'a str, ParsedUnit> {
+        let (rest, (amount, _, unit)) =
+            (map_res(digit1, |s: &str| s.parse::<u32>()), space0, |i| {
+                self.parse_unit_name(i)
+            })
+                .parse(input)?;
+
+        match amount.checked_mul(unit.amount) {
+            Some(amount) => Ok((
+                rest,
+                ParsedUnit {
+                    unit: unit.unit,
+                    amount,
+                },
+            )),
+            None => Err(nom::Err::Error(FlexitimeError::from_error_kind(
+                input,
+                ErrorKind::TooLarge,
+            ))),
+        }
+    }
+
+    pub(crate) fn parse_time<'a>(&self, input: &'a str) -> FlexitimeResult<&'a str, WallClockTime> {
+        find(input, &self.times, false).map(|(rest, time)| (rest, time.clone()))
+    }
+
+    pub(crate) fn parse_date<'a>(&self, input: &'a str) -> FlexitimeResult<&'a str, FlexiDate> {
+        find(input, &self.dates, false).map(|(rest, date)| (rest, date.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use super::*;
+    use crate::{
+        fixtures::{anchor, date, datetime},
+        parser::{
+            FlexiParser,
+            absolute::{Boundary, Period, PeriodBoundary, PeriodTarget},
+        },
+        resolve::ResolveOptions,
+    };
+
+    fn parser() -> FlexiParser {
+        FlexiParser::new().vocabulary(
+            Vocabulary::new()
+                .time("standup", WallClockTime::new(9, 30, 0, None).unwrap())
+                .duration("sprint", RelativeUnit::Weeks, 2)
+                .unit("sol", RelativeUnit::Days)
+                .date(
+                    "eow",
+                    FlexiDate::PeriodBoundary(PeriodBoundary::new(
+                        Boundary::End,
+                        PeriodTarget::Offset {
+                            period: Period::Week,
+                            offset: 0,
+                        },
+                    )),
+                ),
+        )
+    }
+
+    fn resolve(input: &str) -> NaiveDateTime {
+        let (rest, parsed) = parser().parse(input).unwrap();
+        assert_eq!(rest, "", "{input}");
+        parsed
+            .resolve(anchor(), &ResolveOptions::default())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_named_times_and_dates() {
+        assert_eq!(resolve("standup tomorrow"), datetime(2025, 10, 23, 9, 30));
+        assert_eq!(resolve("friday at standup"), datetime(2025, 10, 24, 9, 30));
+        assert_eq!(
+            resolve("EOW"),
+            date(2025, 10, 26).and_hms_opt(23, 59, 59).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_named_units() {
+        assert_eq!(resolve("in 2 sprints"), datetime(2025, 11, 19, 14, 30));
+        assert_eq!(resolve("1 sprint ago"), datetime(2025, 10, 8, 14, 30));
+        assert_eq!(resolve("3 sols ago"), datetime(2025, 10, 19, 14, 30));
+
+        let (_, duration) = parser().parse_duration("1 sprint 2 days").unwrap();
+        assert_eq!(duration.to_std().as_secs(), 16 * 24 * 60 * 60);
+
+        let (_, recurrence) = parser().parse_recurrence("every sprint").unwrap();
+        assert_eq!(recurrence.interval, 2);
+    }
+
+    #[test]
+    fn test_consulted_before_builtins() {
+        let parser = FlexiParser::new().vocabulary(Vocabulary::new().duration(
+            "week",
+            RelativeUnit::Days,
+            5,
+        ));
+
+        let (_, duration) = parser.parse_duration("2 weeks").unwrap();
+        assert_eq!(duration.to_std().as_secs(), 10 * 24 * 60 * 60);
+    }
+}
@@ -0,0 +1,467 @@
+use std::ops::RangeInclusive;
+
+use chrono::{Datelike, Month, NaiveDateTime, Timelike, Weekday};
+
+use super::{ExportError, ImportError, Recurrence, ScheduleFormat, combine_times};
+use crate::{parser::relative::units::RelativeUnit, resolve::ResolveOptions};
+
+fn unrepresentable(reason: &'static str) -> ExportError {
+    ExportError::Unrepresentable {
+        format: ScheduleFormat::Cron,
+        reason,
+    }
+}
+
+fn join(values: impl IntoIterator<Item = u32>) -> String {
+    values
+        .into_iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// A field which steps through `first..=max` every `step` values, starting from the value of
+/// `start`
+fn step_field(start: u32, step: u32, first: u32, max: u32) -> String {
+    let phase = (start - first) % step + first;
+    match (step, phase == first) {
+        (1, _) => "*".to_string(),
+        (_, true) => format!("*/{step}"),
+        (_, false) => format!("{phase}-{max}/{step}"),
+    }
+}
+
+fn weekday_field(weekdays: &[Weekday]) -> String {
+    let mut days = weekdays
+        .iter()
+        .map(Weekday::num_days_from_sunday)
+        .collect::<Vec<_>>();
+    days.sort();
+    days.dedup();
+    join(days)
+}
+
+impl Recurrence {
+    /// Converts the schedule into a five field crontab expression. Cron has no notion of when a
+    /// schedule starts or ends, so the start only determines where intervals are counted from
+    /// and schedules with an end or a count cannot be converted.
+    ///
+    /// The schedule's start is resolved relative to `anchor`.
+    pub fn to_cron(
+        &self,
+        anchor: NaiveDateTime,
+        options: &ResolveOptions,
+    ) -> Result<String, ExportError> {
+        if self.until.is_some() || self.count.is_some() {
+            return Err(unrepresentable("schedules which end"));
+        }
+
+        let (start, _) = self.resolve_bounds(anchor, options)?;
+        let interval = self.interval.max(1);
+
+        let mut weekdays = if self.weekdays.is_empty() {
+            "*".to_string()
+        } else {
+            weekday_field(&self.weekdays)
+        };
+        let mut day = if self.month_days.is_empty() {
+            "*".to_string()
+        } else {
+            if self.month_days.iter().any(|day| *day < 1) {
+                return Err(unrepresentable("days counted from the end of the month"));
+            }
+            join(self.month_days.iter().map(|day| day.unsigned_abs().into()))
+        };
+        let mut month = if self.months.is_empty() {
+            "*".to_string()
+        } else {
+            join(self.months.iter().map(Month::number_from_month))
+        };
+
+        // Cron steps restart at the top of each hour, day or year, so intervals must divide
+        // them evenly
+        let (minute, hour) = match &self.frequency {
+            RelativeUnit::Seconds => return Err(unrepresentable("intervals of seconds")),
+            RelativeUnit::Minutes | RelativeUnit::Hours if start.second() != 0 => {
+                return Err(unrepresentable("starts part way through a minute"));
+            }
+            RelativeUnit::Minutes => {
+                if 60 % interval != 0 {
+                    return Err(unrepresentable("intervals which do not divide an hour"));
+                }
+                (step_field(start.minute(), interval, 0, 59), "*".to_string())
+            }
+            RelativeUnit::Hours => {
+                if 24 % interval != 0 {
+                    return Err(unrepresentable("intervals which do not divide a day"));
+                }
+                (
+                    start.minute().to_string(),
+                    step_field(start.hour(), interval, 0, 23),
+                )
+            }
+            frequency => {
+                match frequency {
+                    RelativeUnit::Days if interval > 1 => {
+                        return Err(unrepresentable("intervals of more than one day"));
+                    }
+                    RelativeUnit::Days => {}
+                    RelativeUnit::BusinessDays => {
+                        if interval > 1 {
+                            return Err(unrepresentable("intervals of more than one business day"));
+                        }
+                        if !options.holidays.holidays().is_empty() {
+                            return Err(unrepresentable("business days which skip holidays"));
+                        }
+
+                        let business_days = [
+                            Weekday::Mon,
+                            Weekday::Tue,
+                            Weekday::Wed,
+                            Weekday::Thu,
+                            Weekday::Fri,
+                            Weekday::Sat,
+                            Weekday::Sun,
+                        ]
+                        .into_iter()
+                        .filter(|weekday| {
+                            !options.weekend.contains(weekday)
+                                && (self.weekdays.is_empty() || self.weekdays.contains(weekday))
+                        })
+                        .collect::<Vec<_>>();
+                        weekdays = weekday_field(&business_days);
+                    }
+                    RelativeUnit::Weeks => {
+                        if interval > 1 {
+                            return Err(unrepresentable("intervals of more than one week"));
+                        }
+                        if self.weekdays.is_empty() {
+                            weekdays = weekday_field(&[start.weekday()]);
+                        }
+                    }
+                    _ => {
+                        let months = match frequency {
+                            RelativeUnit::Months => Some(interval),
+                            RelativeUnit::Quarters => interval.checked_mul(3),
+                            _ => interval.checked_mul(12),
+                        }
+                        .filter(|months| 12 % months == 0)
+                        .ok_or(unrepresentable("intervals which do not divide a year"))?;
+
+                        if self.month_days.is_empty() {
+                            day = start.day().to_string();
+                        }
+                        if months == 12 && self.months.is_empty() {
+                            month = start.month().to_string();
+                        } else if months < 12 {
+                            if !self.months.is_empty() && months > 1 {
+                                return Err(unrepresentable(
+                                    "intervals of months limited to certain months",
+                                ));
+                            }
+                            if self.months.is_empty() {
+                                month = step_field(start.month(), months, 1, 12);
+                            }
+                        }
+                    }
+                }
+
+                let [hours, minutes, seconds] = self.time_fields().ok_or(unrepresentable(
+                    "the times of day do not share the same hours and minutes",
+                ))?;
+                if seconds != [0] {
+                    return Err(unrepresentable("times with seconds"));
+                }
+                (join(minutes), join(hours))
+            }
+        };
+
+        // Cron matches either the day of the month or the day of the week when both are
+        // restricted, rather than requiring both
+        if day != "*" && weekdays != "*" {
+            return Err(unrepresentable(
+                "schedules limited to both days of the month and weekdays",
+            ));
+        }
+
+        Ok(format!("{minute} {hour} {day} {month} {weekdays}"))
+    }
+}
+
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+fn invalid(field: &'static str, value: &str) -> ImportError {
+    ImportError::InvalidField {
+        format: ScheduleFormat::Cron,
+        field,
+        value: value.to_string(),
+    }
+}
+
+fn parse_value(
+    field: &'static str,
+    value: &str,
+    range: &RangeInclusive<u32>,
+    names: &[&str],
+) -> Result<u32, ImportError> {
+    names
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(value))
+        .map(|index| index as u32 + range.start())
+        .or_else(|| value.parse().ok())
+        .filter(|value| range.contains(value))
+        .ok_or_else(|| invalid(field, value))
+}
+
+/// Parses a cron field such as `*/15`, `1-5` or `jan,jul` into the values it matches, or `None`
+/// when it matches every value
+fn parse_field(
+    field: &'static str,
+    value: &str,
+    range: RangeInclusive<u32>,
+    names: &[&str],
+) -> Result<Option<Vec<u32>>, ImportError> {
+    if value == "*" {
+        return Ok(None);
+    }
+
+    let mut values = Vec::new();
+    for item in value.split(',') {
+        let (base, step) = match item.split_once('/') {
+            Some((base, step)) => (
+                base,
+                step.parse::<u32>()
+                    .ok()
+                    .filter(|step| *step > 0)
+                    .ok_or_else(|| invalid(field, item))?,
+            ),
+            None => (item, 1),
+        };
+
+        let (first, last) = match base.split_once('-') {
+            _ if base == "*" => (*range.start(), *range.end()),
+            Some((first, last)) => (
+                parse_value(field, first, &range, names)?,
+                parse_value(field, last, &range, names)?,
+            ),
+            // A single value with a step runs to the end of the range, as in `5/15`
+            None => {
+                let first = parse_value(field, base, &range, names)?;
+                (first, if step > 1 { *range.end() } else { first })
+            }
+        };
+        if first > last {
+            return Err(invalid(field, item));
+        }
+
+        values.extend((first..=last).step_by(step as usize));
+    }
+
+    values.sort();
+    values.dedup();
+    Ok(Some(values))
+}
+
+impl Recurrence {
+    /// Parses a five field crontab expression such as `30 9 * * 1-5`, or one of the `@daily`
+    /// style shorthands. The schedule starts from whatever anchor it is resolved against.
+    pub fn from_cron(input: &str) -> Result<Self, ImportError> {
+        let input = input.trim();
+        let expression = match input.to_ascii_lowercase().as_str() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            "@reboot" => {
+                return Err(ImportError::Unsupported {
+                    format: ScheduleFormat::Cron,
+                    feature: "@reboot",
+                });
+            }
+            _ => input,
+        };
+
+        let fields = expression.split_whitespace().collect::<Vec<_>>();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(invalid("expression", input));
+        };
+
+        // Cron matches either the day of the month or the day of the week when both are
+        // restricted, rather than requiring both
+        if !day.starts_with('*') && !weekday.starts_with('*') {
+            return Err(ImportError::Unsupported {
+                format: ScheduleFormat::Cron,
+                feature: "both a day of the month and a day of the week",
+            });
+        }
+
+        let minutes =
+            parse_field("minute", minute, 0..=59, &[])?.unwrap_or_else(|| (0..=59).collect());
+        let hours = parse_field("hour", hour, 0..=23, &[])?.unwrap_or_else(|| (0..=23).collect());
+        let days = parse_field("day of month", day, 1..=31, &[])?.unwrap_or_default();
+        let months = parse_field("month", month, 1..=12, &MONTH_NAMES)?.unwrap_or_default();
+        let weekdays =
+            parse_field("day of week", weekday, 0..=7, &WEEKDAY_NAMES)?.unwrap_or_default();
+
+        Ok(Recurrence::new(RelativeUnit::Days, 1)
+            .weekdays(
+                weekdays
+                    .into_iter()
+                    .filter_map(|day| Weekday::try_from((day as u8 + 6) % 7).ok()),
+            )
+            .month_days(days.into_iter().map(|day| day as i8))
+            .months(
+                months
+                    .into_iter()
+                    .filter_map(|month| Month::try_from(month as u8).ok()),
+            )
+            .times(combine_times(&hours, &minutes, &[0])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::{
+        fixtures::anchor,
+        parser::absolute::{TimePeriod, WallClockTime},
+    };
+
+    use super::*;
+
+    fn cron(recurrence: Recurrence) -> Result<String, ExportError> {
+        recurrence.to_cron(anchor(), &ResolveOptions::default())
+    }
+
+    #[test]
+    fn test_weekly_cron() {
+        assert_eq!(
+            cron(
+                Recurrence::new(RelativeUnit::Weeks, 1)
+                    .weekdays([Weekday::Mon, Weekday::Fri])
+                    .time(WallClockTime::new(9, 0, 0, Some(TimePeriod::Am)).unwrap())
+            ),
+            Ok("0 9 * * 1,5".to_string())
+        );
+        assert_eq!(
+            cron(Recurrence::new(RelativeUnit::Weeks, 1)),
+            Ok("0 0 * * 3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_interval_cron() {
+        assert_eq!(
+            cron(Recurrence::new(RelativeUnit::Minutes, 15)),
+            Ok("*/15 * * * *".to_string())
+        );
+        assert_eq!(
+            cron(Recurrence::new(RelativeUnit::Hours, 4)),
+            Ok("30 2-23/4 * * *".to_string())
+        );
+        assert_eq!(
+            cron(Recurrence::new(RelativeUnit::Quarters, 1)),
+            Ok("0 0 22 */3 *".to_string())
+        );
+        assert_eq!(
+            cron(
+                Recurrence::new(RelativeUnit::BusinessDays, 1)
+                    .time(WallClockTime::new(8, 30, 0, None).unwrap())
+            ),
+            Ok("30 8 * * 1,2,3,4,5".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unrepresentable_cron() {
+        assert_eq!(
+            cron(Recurrence::new(RelativeUnit::Days, 2)),
+            Err(ExportError::Unrepresentable {
+                format: ScheduleFormat::Cron,
+                reason: "intervals of more than one day"
+            })
+        );
+        assert_eq!(
+            cron(Recurrence::new(RelativeUnit::Days, 1).count(3)),
+            Err(ExportError::Unrepresentable {
+                format: ScheduleFormat::Cron,
+                reason: "schedules which end"
+            })
+        );
+        assert!(cron(Recurrence::new(RelativeUnit::Minutes, 7)).is_err());
+    }
+
+    #[test]
+    fn test_parse_cron() {
+        let recurrence = Recurrence::from_cron("30 9 * * 1-5").unwrap();
+        assert_eq!(
+            recurrence,
+            Recurrence::new(RelativeUnit::Days, 1)
+                .weekdays([
+                    Weekday::Mon,
+                    Weekday::Tue,
+                    Weekday::Wed,
+                    Weekday::Thu,
+                    Weekday::Fri
+                ])
+                .time(WallClockTime::new(9, 30, 0, None).unwrap())
+        );
+        assert_eq!(
+            cron(recurrence.clone()),
+            Ok("30 9 * * 1,2,3,4,5".to_string())
+        );
+
+        let occurrences = recurrence
+            .occurrences(anchor(), &ResolveOptions::default())
+            .unwrap()
+            .take(3)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            occurrences,
+            [23, 24, 27].map(|day| NaiveDate::from_ymd_opt(2025, 10, day)
+                .unwrap()
+                .and_hms_opt(9, 30, 0)
+                .unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_cron_fields() {
+        let recurrence = Recurrence::from_cron("*/20 9-10 1,15 jan,JUL *").unwrap();
+        assert_eq!(recurrence.times.len(), 6);
+        assert_eq!(recurrence.month_days, vec![1, 15]);
+        assert_eq!(recurrence.months, vec![Month::January, Month::July]);
+
+        assert_eq!(
+            Recurrence::from_cron("@weekly"),
+            Ok(Recurrence::new(RelativeUnit::Days, 1)
+                .weekdays([Weekday::Sun])
+                .time(WallClockTime::new(0, 0, 0, None).unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_invalid_cron() {
+        assert_eq!(
+            Recurrence::from_cron("0 0 1 * 1"),
+            Err(ImportError::Unsupported {
+                format: ScheduleFormat::Cron,
+                feature: "both a day of the month and a day of the week"
+            })
+        );
+        assert_eq!(
+            Recurrence::from_cron("61 * * * *"),
+            Err(ImportError::InvalidField {
+                format: ScheduleFormat::Cron,
+                field: "minute",
+                value: "61".to_string()
+            })
+        );
+        assert!(Recurrence::from_cron("* * *").is_err());
+    }
+}
@@ -0,0 +1,656 @@
+use std::collections::VecDeque;
+
+use chrono::{
+    Datelike, Month, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike, Weekday,
+};
+
+use crate::{
+    parser::{
+        ParsedTime,
+        absolute::{AbsoluteTime, FlexiDate, WallClockTime},
+        relative::units::RelativeUnit,
+    },
+    resolve::{ResolveError, ResolveOptions},
+};
+
+mod cron;
+mod rrule;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum_macros::Display)]
+pub enum ScheduleFormat {
+    #[strum(to_string = "an RRULE")]
+    RRule,
+    #[strum(to_string = "a cron expression")]
+    Cron,
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ExportError {
+    #[error(transparent)]
+    Resolve(#[from] ResolveError),
+
+    #[error("The schedule cannot be expressed as {format}: {reason}")]
+    Unrepresentable {
+        format: ScheduleFormat,
+        reason: &'static str,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ImportError {
+    #[error("{field} is required in {format}")]
+    MissingField {
+        format: ScheduleFormat,
+        field: &'static str,
+    },
+
+    #[error("Invalid value '{value}' for {field} in {format}")]
+    InvalidField {
+        format: ScheduleFormat,
+        field: &'static str,
+        value: String,
+    },
+
+    #[error("Unsupported {feature} in {format}")]
+    Unsupported {
+        format: ScheduleFormat,
+        feature: &'static str,
+    },
+}
+
+/// How far iteration searches past the last occurrence before giving up, long enough to find
+/// the 29th of February across a century which skips a leap year
+const MAX_EMPTY_SPAN: TimeDelta = TimeDelta::days(8 * 366);
+
+/// How many periods in a row iteration searches without an occurrence before giving up, one for
+/// each day of [`MAX_EMPTY_SPAN`]
+const MAX_EMPTY_PERIODS: u32 = 8 * 366;
+
+/// A repeating schedule, such as `every monday at 9am` or `every 2 weeks`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recurrence {
+    /// The unit in which the schedule repeats
+    pub frequency: RelativeUnit,
+    /// The number of units between repetitions, e.g. `2` for `every other week`
+    pub interval: u32,
+    /// The days of the week on which the schedule occurs. For weekly schedules these are the
+    /// days within each week, otherwise they restrict which days are included.
+    pub weekdays: Vec<Weekday>,
+    /// The times of day at which the schedule occurs on each day. When empty, daily and longer
+    /// schedules occur at midnight and shorter ones are counted from the start.
+    pub times: Vec<WallClockTime>,
+    /// The days of the month on which the schedule occurs, with negative days counting back
+    /// from the end of the month. For monthly and yearly schedules these are the days within
+    /// each month, otherwise they restrict which days are included.
+    pub month_days: Vec<i8>,
+    /// The months in which the schedule occurs. For yearly schedules these are the months
+    /// within each year, otherwise they restrict which months are included.
+    pub months: Vec<Month>,
+    /// When the schedule starts. Defaults to the anchor it is resolved against.
+    pub start: Option<ParsedTime>,
+    /// The last instant at which the schedule may occur, inclusive
+    pub until: Option<ParsedTime>,
+    /// The maximum number of occurrences
+    pub count: Option<u32>,
+}
+
+impl Recurrence {
+    pub fn new(frequency: RelativeUnit, interval: u32) -> Self {
+        Self {
+            frequency,
+            interval,
+            weekdays: Vec::new(),
+            times: Vec::new(),
+            month_days: Vec::new(),
+            months: Vec::new(),
+            start: None,
+            until: None,
+            count: None,
+        }
+    }
+
+    pub fn weekdays(mut self, weekdays: impl IntoIterator<Item = Weekday>) -> Self {
+        self.weekdays = weekdays.into_iter().collect();
+        self
+    }
+
+    pub fn time(mut self, time: WallClockTime) -> Self {
+        self.times.push(time);
+        self
+    }
+
+    pub fn times(mut self, times: impl IntoIterator<Item = WallClockTime>) -> Self {
+        self.times.extend(times);
+        self
+    }
+
+    pub fn month_days(mut self, month_days: impl IntoIterator<Item = i8>) -> Self {
+        self.month_days = month_days.into_iter().collect();
+        self
+    }
+
+    pub fn months(mut self, months: impl IntoIterator<Item = Month>) -> Self {
+        self.months = months.into_iter().collect();
+        self
+    }
+
+    pub fn start(mut self, start: ParsedTime) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    pub fn until(mut self, until: ParsedTime) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Iterates over the occurrences of the schedule, resolving its start and end relative to
+    /// `anchor`
+    pub fn occurrences(
+        &self,
+        anchor: NaiveDateTime,
+        options: &ResolveOptions,
+    ) -> Result<Occurrences, ResolveError> {
+        let (start, until) = self.resolve_bounds(anchor, options)?;
+
+        Ok(Occurrences {
+            recurrence: self.clone(),
+            options: options.clone(),
+            start,
+            until,
+            remaining: self.count,
+            period: 0,
+            empty_periods: 0,
+            last_found: start.date(),
+            business_day: None,
+            pending: VecDeque::new(),
+        })
+    }
+
+    /// Resolves the start and end of the schedule relative to `anchor`
+    fn resolve_bounds(
+        &self,
+        anchor: NaiveDateTime,
+        options: &ResolveOptions,
+    ) -> Result<(NaiveDateTime, Option<NaiveDateTime>), ResolveError> {
+        let start = match &self.start {
+            Some(start) => start.resolve(anchor, options)?,
+            None => anchor,
+        };
+        let until = self
+            .until
+            .as_ref()
+            .map(|until| until.resolve(anchor, options))
+            .transpose()?;
+
+        Ok((start, until))
+    }
+
+    /// Splits the times of day into hours, minutes and seconds which combine to give exactly
+    /// those times, as both RRULE and cron require. Without any times, daily and longer
+    /// schedules occur at midnight.
+    fn time_fields(&self) -> Option<[Vec<u32>; 3]> {
+        let mut times = self
+            .times
+            .iter()
+            .map(WallClockTime::to_naive_time)
+            .collect::<Vec<_>>();
+        if times.is_empty() {
+            times.push(NaiveTime::MIN);
+        }
+        times.sort();
+        times.dedup();
+
+        let field = |f: fn(&NaiveTime) -> u32| {
+            let mut values = times.iter().map(f).collect::<Vec<_>>();
+            values.sort();
+            values.dedup();
+            values
+        };
+        let fields = [
+            field(NaiveTime::hour),
+            field(NaiveTime::minute),
+            field(NaiveTime::second),
+        ];
+
+        (fields.iter().map(Vec::len).product::<usize>() == times.len()).then_some(fields)
+    }
+}
+
+/// An iterator over the upcoming occurrences of a [`Recurrence`]
+#[derive(Debug, Clone)]
+pub struct Occurrences {
+    recurrence: Recurrence,
+    options: ResolveOptions,
+    start: NaiveDateTime,
+    until: Option<NaiveDateTime>,
+    remaining: Option<u32>,
+    period: u32,
+    empty_periods: u32,
+    last_found: NaiveDate,
+    /// The day of the latest business-day period, from which the next one is counted
+    business_day: Option<NaiveDate>,
+    pending: VecDeque<NaiveDateTime>,
+}
+
+impl Occurrences {
+    fn includes_day(&self, date: NaiveDate) -> bool {
+        let recurrence = &self.recurrence;
+        let weekday_matches = recurrence.frequency == RelativeUnit::Weeks
+            || recurrence.weekdays.is_empty()
+            || recurrence.weekdays.contains(&date.weekday());
+        let month_matches = recurrence.months.is_empty()
+            || recurrence
+                .months
+                .iter()
+                .any(|month| month.number_from_month() == date.month());
+        let month_day_matches = recurrence.month_days.is_empty()
+            || recurrence
+                .month_days
+                .iter()
+                .any(|day| month_day(date.with_day(1), *day) == Some(date));
+
+        weekday_matches && month_matches && month_day_matches
+    }
+
+    /// The days within the month starting on `first` on which the schedule occurs
+    fn days_in_month(&self, first: NaiveDate) -> Vec<NaiveDate> {
+        if self.recurrence.month_days.is_empty() {
+            // Months without the start's day of the month are skipped, as in RFC 5545
+            return first.with_day(self.start.day()).into_iter().collect();
+        }
+
+        let mut days = self
+            .recurrence
+            .month_days
+            .iter()
+            .filter_map(|day| month_day(Some(first), *day))
+            .collect::<Vec<_>>();
+        days.sort();
+        days.dedup();
+        days
+    }
+
+    fn times(&self) -> Vec<NaiveTime> {
+        let mut times = self
+            .recurrence
+            .times
+            .iter()
+            .map(WallClockTime::to_naive_time)
+            .collect::<Vec<_>>();
+        if times.is_empty() {
+            times.push(NaiveTime::MIN);
+        }
+        times.sort();
+        times
+    }
+
+    /// The length of each period of a schedule repeating more often than daily
+    fn sub_daily_step(&self) -> Option<TimeDelta> {
+        let unit = match self.recurrence.frequency {
+            RelativeUnit::Seconds => TimeDelta::seconds(1),
+            RelativeUnit::Minutes => TimeDelta::minutes(1),
+            RelativeUnit::Hours => TimeDelta::hours(1),
+            _ => return None,
+        };
+        unit.checked_mul(self.recurrence.interval.max(1).try_into().ok()?)
+    }
+
+    /// The first day of the next period along with its candidate occurrences, or `None` once
+    /// the period can no longer be represented
+    fn next_period(&mut self) -> Option<(NaiveDate, Vec<NaiveDateTime>)> {
+        let n = self.period;
+        self.period = n.checked_add(1)?;
+        let steps = n.checked_mul(self.recurrence.interval.max(1))?;
+        let start_date = self.start.date();
+
+        let (period_start, days) = match self.recurrence.frequency {
+            RelativeUnit::Seconds | RelativeUnit::Minutes | RelativeUnit::Hours => {
+                let step = self.sub_daily_step()?;
+                let datetime = self
+                    .start
+                    .checked_add_signed(step.checked_mul(n.try_into().ok()?)?)?;
+                let date = datetime.date();
+
+                if !self.includes_day(date) {
+                    // Skip the rest of an excluded day rather than visiting each of its periods
+                    let elapsed = date
+                        .succ_opt()?
+                        .and_time(NaiveTime::MIN)
+                        .signed_duration_since(self.start);
+                    let periods = (elapsed.num_seconds() + step.num_seconds() - 1)
+                        .checked_div(step.num_seconds())?;
+                    self.period = periods.try_into().ok()?;
+                    return Some((date, Vec::new()));
+                }
+                return Some((date, vec![datetime]));
+            }
+            RelativeUnit::Days => {
+                let day = start_date.checked_add_signed(TimeDelta::days(steps.into()))?;
+                (day, vec![day])
+            }
+            RelativeUnit::BusinessDays => {
+                let day = match self.business_day {
+                    Some(previous) => self
+                        .options
+                        .add_business_days(
+                            previous,
+                            self.recurrence.interval.max(1).try_into().ok()?,
+                        )
+                        .ok()?,
+                    None if self.options.is_business_day(start_date) => start_date,
+                    None => self.options.add_business_days(start_date, 1).ok()?,
+                };
+                self.business_day = Some(day);
+                (day, vec![day])
+            }
+            RelativeUnit::Weeks => {
+                let week_start = start_date.checked_sub_signed(TimeDelta::days(
+                    start_date
+                        .weekday()
+                        .days_since(self.options.week_start)
+                        .into(),
+                ))?;
+                let week_start = week_start.checked_add_signed(TimeDelta::weeks(steps.into()))?;
+
+                let weekdays = if self.recurrence.weekdays.is_empty() {
+                    vec![start_date.weekday()]
+                } else {
+                    self.recurrence.weekdays.clone()
+                };
+                let mut days = weekdays
+                    .iter()
+                    .filter_map(|weekday| {
+                        week_start.checked_add_signed(TimeDelta::days(
+                            weekday.days_since(self.options.week_start).into(),
+                        ))
+                    })
+                    .collect::<Vec<_>>();
+                days.sort();
+                days.dedup();
+                (week_start, days)
+            }
+            RelativeUnit::Months | RelativeUnit::Quarters => {
+                let months_per_step = match self.recurrence.frequency {
+                    RelativeUnit::Months => 1,
+                    _ => 3,
+                };
+                let month = start_date
+                    .with_day(1)?
+                    .checked_add_months(Months::new(steps.checked_mul(months_per_step)?))?;
+
+                (month, self.days_in_month(month))
+            }
+            RelativeUnit::Years => {
+                let year = start_date.year().checked_add(steps.try_into().ok()?)?;
+                let mut months = if self.recurrence.months.is_empty() {
+                    vec![start_date.month()]
+                } else {
+                    self.recurrence
+                        .months
+                        .iter()
+                        .map(Month::number_from_month)
+                        .collect()
+                };
+                months.sort();
+                months.dedup();
+
+                let days = months
+                    .into_iter()
+                    .filter_map(|month| NaiveDate::from_ymd_opt(year, month, 1))
+                    .flat_map(|month| self.days_in_month(month))
+                    .collect();
+                (NaiveDate::from_ymd_opt(year, 1, 1)?, days)
+            }
+        };
+
+        let times = self.times();
+        Some((
+            period_start,
+            days.into_iter()
+                .filter(|day| self.includes_day(*day))
+                .flat_map(|day| times.iter().map(move |time| day.and_time(*time)))
+                .collect(),
+        ))
+    }
+}
+
+/// A fixed start or end of a schedule, as used by RRULE and cron
+fn fixed_time(datetime: NaiveDateTime) -> ParsedTime {
+    ParsedTime::Absolute(AbsoluteTime::new(
+        datetime.time().into(),
+        FlexiDate::Date(datetime.date()),
+    ))
+}
+
+/// Every time of day made up of one of each of the hours, minutes and seconds
+fn combine_times(hours: &[u32], minutes: &[u32], seconds: &[u32]) -> Vec<WallClockTime> {
+    let mut times = Vec::new();
+    for hour in hours {
+        for minute in minutes {
+            for second in seconds {
+                times.extend(
+                    NaiveTime::from_hms_opt(*hour, *minute, *second).map(WallClockTime::from),
+                );
+            }
+        }
+    }
+    times
+}
+
+/// The `day`th day of the month starting on `first`, counting back from the end of the month
+/// when `day` is negative
+fn month_day(first: Option<NaiveDate>, day: i8) -> Option<NaiveDate> {
+    let first = first?;
+    let date = if day > 0 {
+        first.with_day(day.unsigned_abs().into())?
+    } else {
+        let last = first.checked_add_months(Months::new(1))?.pred_opt()?;
+        last.checked_sub_signed(TimeDelta::days(i64::from(day.unsigned_abs()) - 1))?
+    };
+
+    (date.month() == first.month()).then_some(date)
+}
+
+impl Iterator for Occurrences {
+    type Item = NaiveDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+
+        while self.pending.is_empty() {
+            let (period_start, candidates) = self.next_period()?;
+
+            self.empty_periods += 1;
+            if self.empty_periods > MAX_EMPTY_PERIODS
+                || period_start.signed_duration_since(self.last_found) > MAX_EMPTY_SPAN
+            {
+                return None;
+            }
+
+            self.pending.extend(
+                candidates
+                    .into_iter()
+                    .filter(|candidate| *candidate >= self.start),
+            );
+            if !self.pending.is_empty() {
+                self.last_found = period_start;
+                self.empty_periods = 0;
+            }
+        }
+
+        let next = self.pending.pop_front()?;
+        if self.until.is_some_and(|until| next > until) {
+            self.remaining = Some(0);
+            return None;
+        }
+
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining -= 1;
+        }
+
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fixtures::{anchor, datetime},
+        parser::absolute::{AbsoluteTimeBuilder, FlexiDate, TimePeriod},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_weekly_on_weekdays() {
+        let recurrence = Recurrence::new(RelativeUnit::Weeks, 1)
+            .weekdays([Weekday::Mon, Weekday::Fri])
+            .time(WallClockTime::new(9, 0, 0, Some(TimePeriod::Am)).unwrap());
+
+        let occurrences = recurrence
+            .occurrences(anchor(), &ResolveOptions::default())
+            .unwrap()
+            .take(3)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            occurrences,
+            vec![
+                datetime(2025, 10, 24, 9, 0),
+                datetime(2025, 10, 27, 9, 0),
+                datetime(2025, 10, 31, 9, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_other_day_with_count() {
+        let occurrences = Recurrence::new(RelativeUnit::Days, 2)
+            .count(3)
+            .occurrences(anchor(), &ResolveOptions::default())
+            .unwrap()
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            occurrences,
+            vec![
+                datetime(2025, 10, 24, 0, 0),
+                datetime(2025, 10, 26, 0, 0),
+                datetime(2025, 10, 28, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hourly_until() {
+        let until = AbsoluteTimeBuilder::new()
+            .time(WallClockTime::new(17, 0, 0, None).unwrap())
+            .build()
+            .unwrap();
+
+        let occurrences = Recurrence::new(RelativeUnit::Hours, 1)
+            .until(ParsedTime::Absolute(until))
+            .occurrences(anchor(), &ResolveOptions::default())
+            .unwrap()
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            occurrences,
+            vec![
+                datetime(2025, 10, 22, 14, 30),
+                datetime(2025, 10, 22, 15, 30),
+                datetime(2025, 10, 22, 16, 30),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_monthly_skips_short_months() {
+        let start = AbsoluteTimeBuilder::new()
+            .date(FlexiDate::Date(
+                NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+            ))
+            .build()
+            .unwrap();
+
+        let occurrences = Recurrence::new(RelativeUnit::Months, 1)
+            .start(ParsedTime::Absolute(start))
+            .occurrences(anchor(), &ResolveOptions::default())
+            .unwrap()
+            .take(2)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            occurrences,
+            vec![datetime(2026, 1, 31, 0, 0), datetime(2026, 3, 31, 0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_hourly_on_weekday_skips_excluded_days() {
+        let occurrences = Recurrence::new(RelativeUnit::Hours, 1)
+            .weekdays([Weekday::Fri])
+            .occurrences(anchor(), &ResolveOptions::default())
+            .unwrap()
+            .take(2)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            occurrences,
+            vec![datetime(2025, 10, 24, 0, 30), datetime(2025, 10, 24, 1, 30)]
+        );
+    }
+
+    #[test]
+    fn test_every_other_business_day() {
+        let occurrences = Recurrence::new(RelativeUnit::BusinessDays, 2)
+            .occurrences(anchor(), &ResolveOptions::default())
+            .unwrap()
+            .take(4)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            occurrences,
+            vec![
+                datetime(2025, 10, 24, 0, 0),
+                datetime(2025, 10, 28, 0, 0),
+                datetime(2025, 10, 30, 0, 0),
+                datetime(2025, 11, 3, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unsatisfiable_schedule_ends() {
+        let mut occurrences = Recurrence::new(RelativeUnit::Days, 7)
+            .weekdays([Weekday::Thu])
+            .occurrences(anchor(), &ResolveOptions::default())
+            .unwrap();
+
+        assert_eq!(occurrences.next(), None);
+    }
+
+    #[test]
+    fn test_unsatisfiable_short_schedules_end() {
+        let mut occurrences = Recurrence::new(RelativeUnit::Seconds, 1)
+            .months([Month::February])
+            .month_days([30])
+            .occurrences(anchor(), &ResolveOptions::default())
+            .unwrap();
+        assert_eq!(occurrences.next(), None);
+
+        let mut occurrences = Recurrence::new(RelativeUnit::BusinessDays, 1)
+            .weekdays([Weekday::Sat])
+            .occurrences(anchor(), &ResolveOptions::default())
+            .unwrap();
+        assert_eq!(occurrences.next(), None);
+    }
+}
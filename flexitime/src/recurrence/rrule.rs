@@ -0,0 +1,642 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use chrono::{Datelike, Month, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+
+use super::{ExportError, ImportError, Recurrence, ScheduleFormat, combine_times, fixed_time};
+use crate::{parser::relative::units::RelativeUnit, resolve::ResolveOptions};
+
+fn unrepresentable(reason: &'static str) -> ExportError {
+    ExportError::Unrepresentable {
+        format: ScheduleFormat::RRule,
+        reason,
+    }
+}
+
+pub(crate) fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn join(values: &[u32]) -> String {
+    values
+        .iter()
+        .map(u32::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+impl Recurrence {
+    /// Converts the schedule into an RFC 5545 `RRULE:` line. The rule describes the occurrences
+    /// which follow the schedule's start, so it should be paired with a `DTSTART` of the first
+    /// occurrence.
+    ///
+    /// The schedule's start and end are resolved relative to `anchor`.
+    pub fn to_rrule(
+        &self,
+        anchor: NaiveDateTime,
+        options: &ResolveOptions,
+    ) -> Result<String, ExportError> {
+        let (start, until) = self.resolve_bounds(anchor, options)?;
+        let mut interval = self.interval.max(1);
+        let mut weekdays = self.weekdays.clone();
+
+        let frequency = match self.frequency {
+            RelativeUnit::Seconds => "SECONDLY",
+            RelativeUnit::Minutes => "MINUTELY",
+            RelativeUnit::Hours => "HOURLY",
+            RelativeUnit::Days => "DAILY",
+            RelativeUnit::BusinessDays => {
+                if interval > 1 {
+                    return Err(unrepresentable("intervals of more than one business day"));
+                }
+                if !options.holidays.holidays().is_empty() {
+                    return Err(unrepresentable("business days which skip holidays"));
+                }
+
+                let business_days = [
+                    Weekday::Mon,
+                    Weekday::Tue,
+                    Weekday::Wed,
+                    Weekday::Thu,
+                    Weekday::Fri,
+                    Weekday::Sat,
+                    Weekday::Sun,
+                ]
+                .into_iter()
+                .filter(|weekday| !options.weekend.contains(weekday));
+                weekdays = if weekdays.is_empty() {
+                    business_days.collect()
+                } else {
+                    business_days
+                        .filter(|weekday| weekdays.contains(weekday))
+                        .collect()
+                };
+                "DAILY"
+            }
+            RelativeUnit::Weeks => "WEEKLY",
+            RelativeUnit::Months => "MONTHLY",
+            RelativeUnit::Quarters => {
+                interval = interval
+                    .checked_mul(3)
+                    .ok_or(unrepresentable("the interval is too large"))?;
+                "MONTHLY"
+            }
+            RelativeUnit::Years => "YEARLY",
+        };
+
+        let mut parts = vec![format!("FREQ={frequency}")];
+        if interval > 1 {
+            parts.push(format!("INTERVAL={interval}"));
+        }
+        if until.is_some() && self.count.is_some() {
+            return Err(unrepresentable("schedules with both an end and a count"));
+        }
+        if let Some(until) = until {
+            parts.push(format!("UNTIL={}", until.format("%Y%m%dT%H%M%S")));
+        }
+        if let Some(count) = self.count {
+            parts.push(format!("COUNT={count}"));
+        }
+
+        // BYDAY and BYMONTHDAY expand monthly and yearly rules to every matching day rather
+        // than limiting them, unless the month and day of the month are also fixed
+        if !self.months.is_empty() {
+            parts.push(format!(
+                "BYMONTH={}",
+                join(
+                    &self
+                        .months
+                        .iter()
+                        .map(Month::number_from_month)
+                        .collect::<Vec<_>>()
+                )
+            ));
+        } else if frequency == "YEARLY" && !(weekdays.is_empty() && self.month_days.is_empty()) {
+            parts.push(format!("BYMONTH={}", start.month()));
+        }
+        if !self.month_days.is_empty() {
+            if frequency == "WEEKLY" {
+                return Err(unrepresentable(
+                    "weekly schedules limited to days of the month",
+                ));
+            }
+            parts.push(format!(
+                "BYMONTHDAY={}",
+                self.month_days
+                    .iter()
+                    .map(i8::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ));
+        } else if matches!(frequency, "MONTHLY" | "YEARLY") && !weekdays.is_empty() {
+            parts.push(format!("BYMONTHDAY={}", start.day()));
+        }
+        if !weekdays.is_empty() {
+            parts.push(format!(
+                "BYDAY={}",
+                weekdays
+                    .iter()
+                    .map(|weekday| weekday_code(*weekday))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ));
+        }
+
+        // Shorter schedules are counted from their start, so have no fixed times of day
+        if !matches!(
+            self.frequency,
+            RelativeUnit::Seconds | RelativeUnit::Minutes | RelativeUnit::Hours
+        ) {
+            let [hours, minutes, seconds] = self.time_fields().ok_or(unrepresentable(
+                "the times of day do not share the same hours, minutes and seconds",
+            ))?;
+            parts.push(format!("BYHOUR={}", join(&hours)));
+            parts.push(format!("BYMINUTE={}", join(&minutes)));
+            parts.push(format!("BYSECOND={}", join(&seconds)));
+        }
+
+        if self.frequency == RelativeUnit::Weeks && options.week_start != Weekday::Mon {
+            parts.push(format!("WKST={}", weekday_code(options.week_start)));
+        }
+
+        Ok(format!("RRULE:{}", parts.join(";")))
+    }
+}
+
+fn invalid(field: &'static str, value: &str) -> ImportError {
+    ImportError::InvalidField {
+        format: ScheduleFormat::RRule,
+        field,
+        value: value.to_string(),
+    }
+}
+
+fn unsupported(feature: &'static str) -> ImportError {
+    ImportError::Unsupported {
+        format: ScheduleFormat::RRule,
+        feature,
+    }
+}
+
+fn parse_weekday_code(field: &'static str, code: &str) -> Result<Weekday, ImportError> {
+    [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ]
+    .into_iter()
+    .find(|weekday| code.eq_ignore_ascii_case(weekday_code(*weekday)))
+    .ok_or_else(|| {
+        // Split by character, as the code may come from anywhere and need not be ASCII
+        let numbered = code.char_indices().rev().nth(1).is_some_and(|(index, _)| {
+            let (number, day) = code.split_at(index);
+            let digits = number.strip_prefix(['+', '-']).unwrap_or(number);
+            !digits.is_empty()
+                && digits.chars().all(|c| c.is_ascii_digit())
+                && parse_weekday_code(field, day).is_ok()
+        });
+
+        if numbered {
+            unsupported("numbered weekdays such as 1MO")
+        } else {
+            invalid(field, code)
+        }
+    })
+}
+
+fn parse_datetime(field: &'static str, value: &str) -> Result<NaiveDateTime, ImportError> {
+    let value = value.trim_end_matches(['Z', 'z']);
+
+    NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+        .or_else(|_| {
+            NaiveDate::parse_from_str(value, "%Y%m%d").map(|date| date.and_time(NaiveTime::MIN))
+        })
+        .map_err(|_| invalid(field, value))
+}
+
+fn parse_number<T: FromStr + PartialOrd>(
+    field: &'static str,
+    value: &str,
+    range: &RangeInclusive<T>,
+) -> Result<T, ImportError> {
+    value
+        .trim()
+        .parse::<T>()
+        .ok()
+        .filter(|number| range.contains(number))
+        .ok_or_else(|| invalid(field, value))
+}
+
+/// Parses a comma-separated list of numbers within `range`
+fn parse_list<T: FromStr + PartialOrd>(
+    field: &'static str,
+    value: &str,
+    range: RangeInclusive<T>,
+) -> Result<Vec<T>, ImportError> {
+    value
+        .split(',')
+        .map(|item| parse_number(field, item, &range))
+        .collect()
+}
+
+impl Recurrence {
+    /// Parses an RFC 5545 recurrence rule such as `FREQ=WEEKLY;BYDAY=MO,FR;BYHOUR=9`. The
+    /// `RRULE:` prefix is optional, and the rule may be preceded by a `DTSTART` line which sets
+    /// the start of the schedule.
+    pub fn from_rrule(input: &str) -> Result<Self, ImportError> {
+        let mut start = None;
+        let mut rule = None;
+
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            match line.split_once(':') {
+                Some((name, value)) => {
+                    // Parameters such as TZID are ignored, as times are resolved without zones
+                    let name = name.split(';').next().unwrap_or_default();
+                    if name.eq_ignore_ascii_case("DTSTART") {
+                        start = Some(parse_datetime("DTSTART", value)?);
+                    } else if name.eq_ignore_ascii_case("RRULE") {
+                        rule = Some(value);
+                    } else {
+                        return Err(invalid("property", name));
+                    }
+                }
+                None => rule = Some(line),
+            }
+        }
+
+        let rule = rule.ok_or(ImportError::MissingField {
+            format: ScheduleFormat::RRule,
+            field: "RRULE",
+        })?;
+
+        let mut frequency = None;
+        let mut interval = 1;
+        let mut until = None;
+        let mut count = None;
+        let mut weekdays = Vec::new();
+        let mut month_days = Vec::new();
+        let mut months = Vec::new();
+        let mut hours = None;
+        let mut minutes = None;
+        let mut seconds = None;
+        let mut week_start = Weekday::Mon;
+
+        for part in rule.split(';').filter(|part| !part.is_empty()) {
+            let (name, value) = part
+                .split_once('=')
+                .ok_or_else(|| invalid("rule part", part))?;
+
+            match name.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "SECONDLY" => RelativeUnit::Seconds,
+                        "MINUTELY" => RelativeUnit::Minutes,
+                        "HOURLY" => RelativeUnit::Hours,
+                        "DAILY" => RelativeUnit::Days,
+                        "WEEKLY" => RelativeUnit::Weeks,
+                        "MONTHLY" => RelativeUnit::Months,
+                        "YEARLY" => RelativeUnit::Years,
+                        _ => return Err(invalid("FREQ", value)),
+                    })
+                }
+                "INTERVAL" => interval = parse_number("INTERVAL", value, &(1..=u32::MAX))?,
+                "COUNT" => count = Some(parse_number("COUNT", value, &(0..=u32::MAX))?),
+                "UNTIL" => until = Some(parse_datetime("UNTIL", value)?),
+                "BYDAY" => {
+                    weekdays = value
+                        .split(',')
+                        .map(|code| parse_weekday_code("BYDAY", code.trim()))
+                        .collect::<Result<_, _>>()?
+                }
+                "BYMONTHDAY" => {
+                    month_days = parse_list("BYMONTHDAY", value, -31..=31)?;
+                    if month_days.contains(&0) {
+                        return Err(invalid("BYMONTHDAY", value));
+                    }
+                }
+                "BYMONTH" => {
+                    months = parse_list("BYMONTH", value, 1..=12)?
+                        .into_iter()
+                        .filter_map(|month: u8| Month::try_from(month).ok())
+                        .collect()
+                }
+                "BYHOUR" => hours = Some(parse_list("BYHOUR", value, 0..=23)?),
+                "BYMINUTE" => minutes = Some(parse_list("BYMINUTE", value, 0..=59)?),
+                "BYSECOND" => seconds = Some(parse_list("BYSECOND", value, 0..=59)?),
+                "WKST" => week_start = parse_weekday_code("WKST", value)?,
+                "BYSETPOS" => return Err(unsupported("BYSETPOS")),
+                "BYYEARDAY" => return Err(unsupported("BYYEARDAY")),
+                "BYWEEKNO" => return Err(unsupported("BYWEEKNO")),
+                _ => return Err(invalid("rule part", name)),
+            }
+        }
+
+        let mut frequency = frequency.ok_or(ImportError::MissingField {
+            format: ScheduleFormat::RRule,
+            field: "FREQ",
+        })?;
+
+        if frequency == RelativeUnit::Weeks && interval > 1 && week_start != Weekday::Mon {
+            return Err(unsupported("weeks which do not start on Monday"));
+        }
+
+        // BYDAY expands monthly and yearly rules to every matching weekday unless the day of the
+        // month is fixed, which is a daily schedule limited to those weekdays
+        if matches!(frequency, RelativeUnit::Months | RelativeUnit::Years)
+            && !weekdays.is_empty()
+            && month_days.is_empty()
+        {
+            if interval > 1 {
+                return Err(unsupported(
+                    "BYDAY without BYMONTHDAY in monthly or yearly rules with an interval",
+                ));
+            }
+            frequency = RelativeUnit::Days;
+        }
+
+        // Likewise BYMONTHDAY expands a yearly rule to every month unless it is limited by
+        // BYMONTH
+        if frequency == RelativeUnit::Years && !month_days.is_empty() && months.is_empty() {
+            months = (1..=12)
+                .filter_map(|month| Month::try_from(month).ok())
+                .collect();
+        }
+
+        let sub_daily = matches!(
+            frequency,
+            RelativeUnit::Seconds | RelativeUnit::Minutes | RelativeUnit::Hours
+        );
+        let times = if hours.is_none() && minutes.is_none() && seconds.is_none() {
+            match start {
+                Some(start) if !sub_daily => vec![start.time().into()],
+                _ => Vec::new(),
+            }
+        } else {
+            if sub_daily {
+                return Err(unsupported("times of day in rules shorter than daily"));
+            }
+
+            // Any part of the time which isn't given is taken from the start
+            let start_time = start.map(|start| start.time()).unwrap_or(NaiveTime::MIN);
+            let hours = hours.unwrap_or_else(|| vec![start_time.hour()]);
+            let minutes = minutes.unwrap_or_else(|| vec![start_time.minute()]);
+            let seconds = seconds.unwrap_or_else(|| vec![start_time.second()]);
+
+            combine_times(&hours, &minutes, &seconds)
+        };
+
+        Ok(Recurrence {
+            frequency,
+            interval,
+            weekdays,
+            times,
+            month_days,
+            months,
+            start: start.map(fixed_time),
+            until: until.map(fixed_time),
+            count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::{
+        fixtures::anchor,
+        holiday::HolidaySet,
+        parser::{
+            ParsedTime,
+            absolute::{AbsoluteTimeBuilder, FlexiDate, TimePeriod, WallClockTime},
+            recurrence::parse_recurrence,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_weekly_rrule() {
+        let recurrence = Recurrence::new(RelativeUnit::Weeks, 2)
+            .weekdays([Weekday::Mon, Weekday::Fri])
+            .time(WallClockTime::new(9, 0, 0, Some(TimePeriod::Am)).unwrap())
+            .count(10);
+
+        assert_eq!(
+            recurrence.to_rrule(anchor(), &ResolveOptions::default()),
+            Ok(
+                "RRULE:FREQ=WEEKLY;INTERVAL=2;COUNT=10;BYDAY=MO,FR;BYHOUR=9;BYMINUTE=0;BYSECOND=0"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            recurrence.to_rrule(
+                anchor(),
+                &ResolveOptions::default().week_start(Weekday::Sun)
+            ),
+            Ok("RRULE:FREQ=WEEKLY;INTERVAL=2;COUNT=10;BYDAY=MO,FR;BYHOUR=9;BYMINUTE=0;BYSECOND=0;WKST=SU".to_string())
+        );
+    }
+
+    #[test]
+    fn test_rrule_until_and_quarters() {
+        let until = AbsoluteTimeBuilder::new()
+            .date(FlexiDate::Date(
+                NaiveDate::from_ymd_opt(2026, 12, 31).unwrap(),
+            ))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            Recurrence::new(RelativeUnit::Quarters, 1)
+                .until(ParsedTime::Absolute(until))
+                .to_rrule(anchor(), &ResolveOptions::default()),
+            Ok(
+                "RRULE:FREQ=MONTHLY;INTERVAL=3;UNTIL=20261231T000000;BYHOUR=0;BYMINUTE=0;BYSECOND=0"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_rrule_until_and_count() {
+        let recurrence = parse_recurrence("every day until 31/12/2025 for 5 times")
+            .unwrap()
+            .1;
+
+        assert_eq!(
+            recurrence.to_rrule(anchor(), &ResolveOptions::default()),
+            Err(unrepresentable("schedules with both an end and a count"))
+        );
+    }
+
+    #[test]
+    fn test_rrule_business_days() {
+        assert_eq!(
+            Recurrence::new(RelativeUnit::BusinessDays, 1)
+                .to_rrule(anchor(), &ResolveOptions::default()),
+            Ok("RRULE:FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR;BYHOUR=0;BYMINUTE=0;BYSECOND=0".to_string())
+        );
+        assert_eq!(
+            Recurrence::new(RelativeUnit::BusinessDays, 1).to_rrule(
+                anchor(),
+                &ResolveOptions::default().holidays(HolidaySet::builtin())
+            ),
+            Err(ExportError::Unrepresentable {
+                format: ScheduleFormat::RRule,
+                reason: "business days which skip holidays"
+            })
+        );
+    }
+
+    #[test]
+    fn test_rrule_mismatched_times() {
+        let recurrence = Recurrence::new(RelativeUnit::Days, 1)
+            .time(WallClockTime::new(9, 0, 0, None).unwrap())
+            .time(WallClockTime::new(17, 30, 0, None).unwrap());
+
+        assert!(matches!(
+            recurrence.to_rrule(anchor(), &ResolveOptions::default()),
+            Err(ExportError::Unrepresentable { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_rrule() {
+        let recurrence = Recurrence::from_rrule(
+            "DTSTART:20251020T090000\nRRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE",
+        )
+        .unwrap();
+
+        let occurrences = recurrence
+            .occurrences(anchor(), &ResolveOptions::default())
+            .unwrap()
+            .take(3)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            occurrences,
+            [(10, 20), (10, 22), (11, 3)].map(|(month, day)| NaiveDate::from_ymd_opt(
+                2025, month, day
+            )
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap())
+        );
+    }
+
+    #[test]
+    fn test_rrule_round_trip() {
+        let recurrence = Recurrence::new(RelativeUnit::Weeks, 2)
+            .weekdays([Weekday::Mon, Weekday::Fri])
+            .time(WallClockTime::new(9, 0, 0, Some(TimePeriod::Am)).unwrap())
+            .count(10);
+
+        let rrule = recurrence
+            .to_rrule(anchor(), &ResolveOptions::default())
+            .unwrap();
+        assert_eq!(Recurrence::from_rrule(&rrule), Ok(recurrence));
+    }
+
+    #[test]
+    fn test_rrule_month_days() {
+        let occurrences = Recurrence::from_rrule("FREQ=MONTHLY;BYMONTHDAY=-1;COUNT=3")
+            .unwrap()
+            .occurrences(anchor(), &ResolveOptions::default())
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            occurrences,
+            [(2025, 10, 31), (2025, 11, 30), (2025, 12, 31)].map(|(year, month, day)| {
+                NaiveDate::from_ymd_opt(year, month, day)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
+            })
+        );
+
+        let mut leap_days = Recurrence::from_rrule("RRULE:FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=29")
+            .unwrap()
+            .occurrences(anchor(), &ResolveOptions::default())
+            .unwrap();
+        assert_eq!(
+            leap_days.next(),
+            NaiveDate::from_ymd_opt(2028, 2, 29)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_unsupported_rrule() {
+        assert_eq!(
+            Recurrence::from_rrule("FREQ=MONTHLY;BYDAY=1MO"),
+            Err(ImportError::Unsupported {
+                format: ScheduleFormat::RRule,
+                feature: "numbered weekdays such as 1MO"
+            })
+        );
+        assert_eq!(
+            Recurrence::from_rrule("FREQ=MONTHLY;BYDAY=-2FR"),
+            Err(ImportError::Unsupported {
+                format: ScheduleFormat::RRule,
+                feature: "numbered weekdays such as 1MO"
+            })
+        );
+        assert_eq!(
+            Recurrence::from_rrule("INTERVAL=2"),
+            Err(ImportError::MissingField {
+                format: ScheduleFormat::RRule,
+                field: "FREQ"
+            })
+        );
+        assert_eq!(
+            Recurrence::from_rrule("FREQ=MONTHLY;BYDAY=FR")
+                .unwrap()
+                .frequency,
+            RelativeUnit::Days
+        );
+    }
+
+    #[test]
+    fn test_invalid_weekday_codes() {
+        let invalid = |field, value: &str| {
+            Err(ImportError::InvalidField {
+                format: ScheduleFormat::RRule,
+                field,
+                value: value.to_string(),
+            })
+        };
+
+        assert_eq!(
+            Recurrence::from_rrule("FREQ=WEEKLY;BYDAY=éx"),
+            invalid("BYDAY", "éx")
+        );
+        assert_eq!(
+            Recurrence::from_rrule("FREQ=WEEKLY;BYDAY=aé"),
+            invalid("BYDAY", "aé")
+        );
+        assert_eq!(
+            Recurrence::from_rrule("FREQ=WEEKLY;WKST=éé"),
+            invalid("WKST", "éé")
+        );
+        assert_eq!(
+            Recurrence::from_rrule("FREQ=WEEKLY;BYDAY=1XX"),
+            invalid("BYDAY", "1XX")
+        );
+        assert_eq!(
+            Recurrence::from_rrule("FREQ=WEEKLY;BYDAY=é"),
+            invalid("BYDAY", "é")
+        );
+    }
+}
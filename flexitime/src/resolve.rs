@@ -0,0 +1,493 @@
+use std::{ops::Sub, sync::Arc};
+
+use chrono::{
+    DateTime, Datelike, MappedLocalTime, Month, Months, NaiveDate, NaiveDateTime, Offset,
+    TimeDelta, TimeZone, Weekday,
+};
+
+use crate::holiday::{HolidayCalendar, HolidaySet};
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ResolveError {
+    #[error("The resolved time is outside of the supported range")]
+    OutOfRange,
+
+    #[error("{year}-{month:02} has no occurrence {n} of {weekday}")]
+    NoSuchWeekdayOccurrence {
+        n: i8,
+        weekday: Weekday,
+        year: i32,
+        month: u32,
+    },
+
+    #[error("{year} has no week {week}")]
+    NoSuchWeek { week: u8, year: i32 },
+
+    #[error("{year}-{month:02} has no day {day}")]
+    NoSuchDay { year: i32, month: u32, day: u32 },
+
+    #[error("The holiday '{name}' does not occur in {year}")]
+    HolidayNotObserved { name: String, year: i32 },
+
+    #[error("No business day could be found, as every day is a weekend or holiday")]
+    NoBusinessDays,
+
+    #[error("{0} does not exist in the time zone, as the clocks go forward over it")]
+    NonexistentLocalTime(NaiveDateTime),
+
+    #[error("{0} is ambiguous in the time zone, as the clocks go back over it")]
+    AmbiguousLocalTime(NaiveDateTime),
+
+    #[error("The range starts at {start}, after it ends at {end}")]
+    InvertedRange {
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+    },
+}
+
+/// Which occurrence of an underspecified time, such as `3pm`, `friday` or `december`, is meant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Preference {
+    /// The next occurrence, as when scheduling
+    #[default]
+    Future,
+    /// The most recent occurrence, as when searching through logs
+    Past,
+    /// Whichever occurrence is closest to the anchor, preferring the next one when both are as
+    /// close
+    Nearest,
+}
+
+impl Preference {
+    /// Picks between the occurrences of a time either side of `anchor`
+    pub(crate) fn choose<T>(
+        self,
+        anchor: T,
+        past: impl FnOnce() -> Result<T, ResolveError>,
+        future: impl FnOnce() -> Result<T, ResolveError>,
+    ) -> Result<T, ResolveError>
+    where
+        T: Copy + Sub<Output = TimeDelta>,
+    {
+        match self {
+            Preference::Future => future(),
+            Preference::Past => past(),
+            Preference::Nearest => match (past(), future()) {
+                (Ok(past), Ok(future)) if anchor - past < future - anchor => Ok(past),
+                (past, future) => future.or(past),
+            },
+        }
+    }
+}
+
+/// Which instant a local time means when it is skipped as the clocks go forward, such as
+/// `2:30am` on the night daylight saving time starts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GapPolicy {
+    /// The time read with the offset from after the change, which is earlier than the time
+    /// given, e.g. `1:30am`
+    Earliest,
+    /// The time read with the offset from before the change, which is later than the time given,
+    /// e.g. `3:30am`. This is how iCalendar and JavaScript treat such times.
+    #[default]
+    Latest,
+    /// The instant at which the clocks change, e.g. `3:00am`
+    ShiftForward,
+    /// Fail with [`ResolveError::NonexistentLocalTime`]
+    Error,
+}
+
+/// Which instant a local time means when it occurs twice as the clocks go back, such as `1:30am`
+/// on the night daylight saving time ends
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FoldPolicy {
+    /// The first occurrence, before the clocks go back
+    #[default]
+    Earliest,
+    /// The second occurrence, after the clocks go back
+    Latest,
+    /// Fail with [`ResolveError::AmbiguousLocalTime`]
+    Error,
+}
+
+/// How days and weeks are added to a time in a time zone where the length of a day can change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DayArithmetic {
+    /// Keep the same local time, so `in 1 day` is 23 or 25 hours away across a change of the
+    /// clocks
+    #[default]
+    Calendar,
+    /// Add exactly 24 hours per day, so the local time may change by an hour
+    Exact,
+}
+
+/// How months and years are added to a day which the month they land in does not have, such as
+/// 31 January plus one month
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MonthArithmetic {
+    /// Use the last day of the month instead, so 31 January plus one month is 28 February
+    #[default]
+    Clamp,
+    /// Carry the missing days into the following month, so 31 January plus one month is
+    /// 3 March
+    Overflow,
+    /// Keep the last day of a month on the last day of the month, so 28 February plus one month
+    /// is 31 March. Other days are clamped.
+    PreserveEndOfMonth,
+    /// Fail with [`ResolveError::NoSuchDay`]
+    Error,
+}
+
+/// The last day of the month containing `date`
+fn last_day_of_month(date: NaiveDate) -> Option<NaiveDate> {
+    date.with_day(1)?
+        .checked_add_months(Months::new(1))?
+        .pred_opt()
+}
+
+/// Settings which influence how a parsed time is turned into a concrete instant
+#[derive(Debug, Clone)]
+pub struct ResolveOptions {
+    /// The day on which weeks are considered to start
+    pub week_start: Weekday,
+    /// The month in which fiscal years start. Quarters are counted from this month and fiscal
+    /// years are named after the calendar year in which they end.
+    pub fiscal_year_start: Month,
+    /// The days of the week which are not business days
+    pub weekend: Vec<Weekday>,
+    /// Holidays which are not business days. No holidays are observed by default.
+    pub holidays: Arc<dyn HolidayCalendar>,
+    /// Which occurrence of a time of day, weekday, month or holiday is meant when it is given
+    /// without a date or year. Without a preference, times of day fall on the anchor's day and
+    /// everything else looks forward.
+    pub preference: Option<Preference>,
+    /// How local times skipped by a change of the clocks are resolved in a time zone
+    pub gap_policy: GapPolicy,
+    /// How local times repeated by a change of the clocks are resolved in a time zone
+    pub fold_policy: FoldPolicy,
+    /// Whether days and weeks follow the calendar or elapsed time when resolving in a time zone
+    pub day_arithmetic: DayArithmetic,
+    /// How months, quarters and years are added to days near the end of a month
+    pub month_arithmetic: MonthArithmetic,
+}
+
+impl Default for ResolveOptions {
+    fn default() -> Self {
+        Self {
+            week_start: Weekday::Mon,
+            fiscal_year_start: Month::January,
+            weekend: vec![Weekday::Sat, Weekday::Sun],
+            holidays: Arc::new(HolidaySet::new()),
+            preference: None,
+            gap_policy: GapPolicy::default(),
+            fold_policy: FoldPolicy::default(),
+            day_arithmetic: DayArithmetic::default(),
+            month_arithmetic: MonthArithmetic::default(),
+        }
+    }
+}
+
+impl ResolveOptions {
+    pub fn new() -> Self {
+        ResolveOptions::default()
+    }
+
+    pub fn week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    pub fn fiscal_year_start(mut self, fiscal_year_start: Month) -> Self {
+        self.fiscal_year_start = fiscal_year_start;
+        self
+    }
+
+    pub fn weekend(mut self, weekend: impl IntoIterator<Item = Weekday>) -> Self {
+        self.weekend = weekend.into_iter().collect();
+        self
+    }
+
+    pub fn holidays(mut self, calendar: impl HolidayCalendar + 'static) -> Self {
+        self.holidays = Arc::new(calendar);
+        self
+    }
+
+    pub fn prefer(mut self, preference: Preference) -> Self {
+        self.preference = Some(preference);
+        self
+    }
+
+    pub fn gap_policy(mut self, policy: GapPolicy) -> Self {
+        self.gap_policy = policy;
+        self
+    }
+
+    pub fn fold_policy(mut self, policy: FoldPolicy) -> Self {
+        self.fold_policy = policy;
+        self
+    }
+
+    pub fn day_arithmetic(mut self, arithmetic: DayArithmetic) -> Self {
+        self.day_arithmetic = arithmetic;
+        self
+    }
+
+    pub fn month_arithmetic(mut self, arithmetic: MonthArithmetic) -> Self {
+        self.month_arithmetic = arithmetic;
+        self
+    }
+
+    /// Moves `date` forwards (or backwards, if negative) by a number of months, following the
+    /// month arithmetic policy when the day does not exist in the month it lands in
+    pub fn add_months(&self, date: NaiveDate, months: i32) -> Result<NaiveDate, ResolveError> {
+        let first = date.with_day(1).ok_or(ResolveError::OutOfRange)?;
+        let first = if months < 0 {
+            first.checked_sub_months(Months::new(months.unsigned_abs()))
+        } else {
+            first.checked_add_months(Months::new(months.unsigned_abs()))
+        }
+        .ok_or(ResolveError::OutOfRange)?;
+        let last = last_day_of_month(first).ok_or(ResolveError::OutOfRange)?;
+
+        let is_end_of_month = Some(date) == last_day_of_month(date);
+        let day = match self.month_arithmetic {
+            MonthArithmetic::PreserveEndOfMonth if is_end_of_month => last.day(),
+            _ if date.day() <= last.day() => date.day(),
+            MonthArithmetic::Clamp | MonthArithmetic::PreserveEndOfMonth => last.day(),
+            MonthArithmetic::Overflow => {
+                return first
+                    .checked_add_signed(TimeDelta::days((date.day() - 1).into()))
+                    .ok_or(ResolveError::OutOfRange);
+            }
+            MonthArithmetic::Error => {
+                return Err(ResolveError::NoSuchDay {
+                    year: first.year(),
+                    month: first.month(),
+                    day: date.day(),
+                });
+            }
+        };
+
+        first.with_day(day).ok_or(ResolveError::OutOfRange)
+    }
+
+    /// Finds the instant in `zone` at which the local time `local` occurs, following the gap and
+    /// fold policies when the clocks change around it
+    pub fn localize<Tz: TimeZone>(
+        &self,
+        zone: &Tz,
+        local: NaiveDateTime,
+    ) -> Result<DateTime<Tz>, ResolveError> {
+        match zone.from_local_datetime(&local) {
+            MappedLocalTime::Single(time) => Ok(time),
+            MappedLocalTime::Ambiguous(earliest, latest) => match self.fold_policy {
+                FoldPolicy::Earliest => Ok(earliest),
+                FoldPolicy::Latest => Ok(latest),
+                FoldPolicy::Error => Err(ResolveError::AmbiguousLocalTime(local)),
+            },
+            MappedLocalTime::None => {
+                let offset_at = |utc: NaiveDateTime| zone.offset_from_utc_datetime(&utc).fix();
+                // A day either side is clear of the change, which lasts at most a few hours
+                let before = offset_at(local - TimeDelta::days(1));
+                let after = offset_at(local + TimeDelta::days(1));
+                let earliest = local - after;
+                let latest = local - before;
+
+                let utc = match self.gap_policy {
+                    GapPolicy::Earliest => earliest,
+                    GapPolicy::Latest => latest,
+                    GapPolicy::ShiftForward => {
+                        // The clocks change somewhere in (earliest, latest]
+                        let (mut low, mut high) = (earliest, latest);
+                        while high - low > TimeDelta::seconds(1) {
+                            let middle = low + TimeDelta::seconds((high - low).num_seconds() / 2);
+                            if offset_at(middle) == before {
+                                low = middle;
+                            } else {
+                                high = middle;
+                            }
+                        }
+                        high
+                    }
+                    GapPolicy::Error => return Err(ResolveError::NonexistentLocalTime(local)),
+                };
+
+                Ok(zone.from_utc_datetime(&utc))
+            }
+        }
+    }
+
+    /// Whether `date` is neither on the weekend nor a holiday
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.weekend.contains(&date.weekday()) && !self.holidays.is_holiday(date)
+    }
+
+    /// Moves `date` forwards (or backwards, if negative) by a number of business days, stepping
+    /// over weekends and holidays one day at a time
+    pub fn add_business_days(&self, date: NaiveDate, days: i32) -> Result<NaiveDate, ResolveError> {
+        let step = TimeDelta::days(days.signum().into());
+        let mut date = date;
+        let mut remaining = days.unsigned_abs();
+        let mut skipped = 0;
+
+        while remaining > 0 {
+            date = date
+                .checked_add_signed(step)
+                .ok_or(ResolveError::OutOfRange)?;
+
+            if self.is_business_day(date) {
+                remaining -= 1;
+                skipped = 0;
+            } else {
+                skipped += 1;
+                if skipped > 366 {
+                    return Err(ResolveError::NoBusinessDays);
+                }
+            }
+        }
+
+        Ok(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono_tz::{America::New_York, Europe::London};
+
+    use super::*;
+    use crate::fixtures::date;
+
+    fn local(month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_add_months() {
+        let add = |arithmetic, from, months| {
+            ResolveOptions::new()
+                .month_arithmetic(arithmetic)
+                .add_months(from, months)
+        };
+        let cases = [
+            (
+                date(2025, 1, 31),
+                1,
+                [(2025, 2, 28), (2025, 3, 3), (2025, 2, 28)],
+            ),
+            (
+                date(2024, 2, 29),
+                12,
+                [(2025, 2, 28), (2025, 3, 1), (2025, 2, 28)],
+            ),
+            (
+                date(2025, 2, 28),
+                1,
+                [(2025, 3, 28), (2025, 3, 28), (2025, 3, 31)],
+            ),
+            (
+                date(2025, 4, 30),
+                -2,
+                [(2025, 2, 28), (2025, 3, 2), (2025, 2, 28)],
+            ),
+            (
+                date(2025, 3, 15),
+                1,
+                [(2025, 4, 15), (2025, 4, 15), (2025, 4, 15)],
+            ),
+        ];
+
+        for (from, months, [clamp, overflow, end_of_month]) in cases {
+            let arithmetics = [
+                MonthArithmetic::Clamp,
+                MonthArithmetic::Overflow,
+                MonthArithmetic::PreserveEndOfMonth,
+            ];
+            for (arithmetic, (year, month, day)) in
+                arithmetics.into_iter().zip([clamp, overflow, end_of_month])
+            {
+                assert_eq!(
+                    add(arithmetic, from, months),
+                    Ok(date(year, month, day)),
+                    "{from} + {months} months with {arithmetic:?}"
+                );
+            }
+        }
+
+        assert_eq!(
+            add(MonthArithmetic::Error, date(2025, 1, 31), 1),
+            Err(ResolveError::NoSuchDay {
+                year: 2025,
+                month: 2,
+                day: 31
+            })
+        );
+        assert_eq!(
+            add(MonthArithmetic::Error, date(2025, 1, 30), 2),
+            Ok(date(2025, 3, 30))
+        );
+    }
+
+    #[test]
+    fn test_localize_gap() {
+        // New York skips from 2am to 3am on 9 March 2025
+        let skipped = local(3, 9, 2, 30);
+        let localize = |policy| {
+            ResolveOptions::new()
+                .gap_policy(policy)
+                .localize(&New_York, skipped)
+                .map(|time| time.to_rfc3339())
+        };
+
+        assert_eq!(
+            localize(GapPolicy::Earliest),
+            Ok("2025-03-09T01:30:00-05:00".to_string())
+        );
+        assert_eq!(
+            localize(GapPolicy::Latest),
+            Ok("2025-03-09T03:30:00-04:00".to_string())
+        );
+        assert_eq!(
+            localize(GapPolicy::ShiftForward),
+            Ok("2025-03-09T03:00:00-04:00".to_string())
+        );
+        assert_eq!(
+            localize(GapPolicy::Error),
+            Err(ResolveError::NonexistentLocalTime(skipped))
+        );
+    }
+
+    #[test]
+    fn test_localize_fold() {
+        // London repeats 1am to 2am on 26 October 2025
+        let repeated = local(10, 26, 1, 30);
+        let localize = |policy| {
+            ResolveOptions::new()
+                .fold_policy(policy)
+                .localize(&London, repeated)
+                .map(|time| time.to_rfc3339())
+        };
+
+        assert_eq!(
+            localize(FoldPolicy::Earliest),
+            Ok("2025-10-26T01:30:00+01:00".to_string())
+        );
+        assert_eq!(
+            localize(FoldPolicy::Latest),
+            Ok("2025-10-26T01:30:00+00:00".to_string())
+        );
+        assert_eq!(
+            localize(FoldPolicy::Error),
+            Err(ResolveError::AmbiguousLocalTime(repeated))
+        );
+
+        assert_eq!(
+            ResolveOptions::new()
+                .localize(&London, local(10, 27, 1, 30))
+                .map(|time| time.to_rfc3339()),
+            Ok("2025-10-27T01:30:00+00:00".to_string())
+        );
+    }
+}
@@ -0,0 +1,199 @@
+//! Modules for use with `#[serde(with = "...")]`, which store times and durations as the strings
+//! a user would type rather than as their structure.
+//!
+//! ```
+//! # use std::time::Duration;
+//! #[derive(serde::Deserialize)]
+//! struct Config {
+//!     #[serde(with = "flexitime::serde::duration")]
+//!     timeout: Duration,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{ "timeout": "2h 30m" }"#).unwrap();
+//! assert_eq!(config.timeout, Duration::from_secs(9000));
+//! ```
+
+use std::fmt::Display;
+
+use ::serde::{Deserialize, Deserializer, de::Error};
+
+use crate::error::FlexitimeResult;
+
+/// Parses the whole of `input`, failing if any of it is left over
+fn parse_complete<'de, D, T>(
+    deserializer: D,
+    expected: &str,
+    parse: impl FnOnce(&str) -> FlexitimeResult<&str, T>,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let input = String::deserialize(deserializer)?;
+
+    match parse(input.trim()) {
+        Ok(("", value)) => Ok(value),
+        Ok((rest, _)) => Err(D::Error::custom(format!(
+            "unexpected '{rest}' after {expected} in '{input}'"
+        ))),
+        Err(err) => Err(D::Error::custom(format!(
+            "'{input}' is not {expected}: {err:?}"
+        ))),
+    }
+}
+
+fn serialize_display<S>(value: &impl Display, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ::serde::Serializer,
+{
+    serializer.collect_str(value)
+}
+
+/// Stores a [`ParsedTime`](crate::parser::ParsedTime) as a time string, e.g. `friday at 21:00`
+pub mod timestring {
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::parser::{ParsedTime, parse_timestring};
+
+    pub fn serialize<S: Serializer>(time: &ParsedTime, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize_display(time, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ParsedTime, D::Error> {
+        super::parse_complete(deserializer, "a time", parse_timestring)
+    }
+}
+
+/// Stores a [`RelativeTime`](crate::parser::relative::time::RelativeTime) as a string, e.g.
+/// `in 2 days` or `3 hours ago`
+pub mod relative {
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::parser::relative::{parse_relative_time, time::RelativeTime};
+
+    pub fn serialize<S: Serializer>(time: &RelativeTime, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize_display(time, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RelativeTime, D::Error> {
+        super::parse_complete(deserializer, "a relative time", parse_relative_time)
+    }
+}
+
+/// Stores a [`Duration`](std::time::Duration) as a string, e.g. `1h 30m`. Durations with
+/// sub-second precision cannot be serialized.
+pub mod duration {
+    use std::time::Duration;
+
+    use ::serde::{Deserializer, Serializer, ser::Error};
+    use chrono::TimeDelta;
+
+    use crate::{
+        humanize::{HumanizeOptions, Style, humanize_duration},
+        parser::parse_duration,
+    };
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        if duration.subsec_nanos() != 0 {
+            return Err(S::Error::custom(format!(
+                "{duration:?} is more precise than a whole number of seconds"
+            )));
+        }
+        let duration = TimeDelta::from_std(*duration)
+            .map_err(|_err| S::Error::custom(format!("{duration:?} is too long")))?;
+
+        let options = HumanizeOptions::new()
+            .style(Style::Short)
+            .max_units(usize::MAX);
+        serializer.collect_str(&humanize_duration(duration, &options))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        super::parse_complete(deserializer, "a duration", parse_duration)
+            .map(|duration| duration.to_std())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use ::serde::{Deserialize, Serialize};
+
+    use crate::parser::{
+        ParsedTime,
+        absolute::{AbsoluteTimeBuilder, DayOffset, FlexiDate, WallClockTime},
+        relative::time::RelativeTime,
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "super::duration")]
+        timeout: Duration,
+        #[serde(with = "super::relative")]
+        expires: RelativeTime,
+        #[serde(with = "super::timestring")]
+        deadline: ParsedTime,
+    }
+
+    fn deadline() -> ParsedTime {
+        ParsedTime::Absolute(
+            AbsoluteTimeBuilder::new()
+                .date(FlexiDate::DayOffset(DayOffset::Fixed(1)))
+                .time(WallClockTime::new(17, 0, 0, None).unwrap())
+                .build()
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_string_forms() {
+        let config: Config = serde_json::from_str(
+            r#"{ "timeout": "2h 30m", "expires": "in 3 days", "deadline": "tomorrow 5pm" }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                timeout: Duration::from_secs(9000),
+                expires: RelativeTime::new().days(3),
+                deadline: deadline(),
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&config).unwrap(),
+            r#"{"timeout":"2h 30m","expires":"in 3 days","deadline":"tomorrow at 17:00"}"#
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_strings() {
+        let invalid = [
+            r#"{ "timeout": "2 months", "expires": "in 3 days", "deadline": "tomorrow" }"#,
+            r#"{ "timeout": "2h", "expires": "in 3 days extra", "deadline": "tomorrow" }"#,
+            r#"{ "timeout": "2h", "expires": "in 3 days", "deadline": "whenever" }"#,
+        ];
+
+        for input in invalid {
+            assert!(serde_json::from_str::<Config>(input).is_err(), "{input}");
+        }
+        assert!(
+            super::duration::serialize(
+                &Duration::from_millis(1500),
+                &mut serde_json::Serializer::new(Vec::new())
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_structured_form() {
+        let json = serde_json::to_string(&deadline()).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ParsedTime>(&json).unwrap(),
+            deadline()
+        );
+    }
+}